@@ -0,0 +1,12 @@
+-- Your SQL goes here
+-- A goal with no subject applies to the whole period (the sum of every subject).
+CREATE TABLE goals (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    period_id    INTEGER NOT NULL,
+    subject_id   INTEGER,
+    effective_from   DATE NOT NULL,
+    daily_time   INTEGER,
+    weekly_time  INTEGER,
+    FOREIGN KEY (period_id) REFERENCES periods,
+    FOREIGN KEY (subject_id) REFERENCES subjects
+);
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE exams (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    subject_id   INTEGER NOT NULL,
+    date   DATE NOT NULL,
+    kind   TEXT NOT NULL,
+    weight REAL,
+    description TEXT,
+    FOREIGN KEY (subject_id) REFERENCES subjects
+);
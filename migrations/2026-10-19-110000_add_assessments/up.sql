@@ -0,0 +1,13 @@
+-- Your SQL goes here
+-- Weights are relative between the assessments of the same subject. A minimum score, if any, is required to pass.
+CREATE TABLE assessments (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    subject_id   INTEGER NOT NULL,
+    name   TEXT NOT NULL,
+    weight REAL NOT NULL,
+    score  REAL,
+    max_score  REAL NOT NULL DEFAULT 10,
+    min_score  REAL,
+    date   DATE,
+    FOREIGN KEY (subject_id) REFERENCES subjects
+);
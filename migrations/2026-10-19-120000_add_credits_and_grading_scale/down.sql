@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE subjects DROP COLUMN credits;
+ALTER TABLE subjects DROP COLUMN passed;
+ALTER TABLE periods DROP COLUMN grading_scale;
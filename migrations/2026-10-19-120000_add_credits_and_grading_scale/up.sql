@@ -0,0 +1,5 @@
+-- Your SQL goes here
+-- A NULL pass/fail state means it is determined by the final score and the pass mark of the grading scale.
+ALTER TABLE subjects ADD COLUMN credits REAL;
+ALTER TABLE subjects ADD COLUMN passed BOOLEAN;
+ALTER TABLE periods ADD COLUMN grading_scale TEXT NOT NULL DEFAULT '0-10';
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+-- Breaks are non-teaching date ranges (holidays, exam-only weeks...) inside a period.
+CREATE TABLE breaks (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    period_id    INTEGER NOT NULL,
+    start_date   DATE NOT NULL,
+    end_date   DATE NOT NULL,
+    label  TEXT NOT NULL,
+    FOREIGN KEY (period_id) REFERENCES periods
+);
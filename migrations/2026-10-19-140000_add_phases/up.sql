@@ -0,0 +1,12 @@
+-- Your SQL goes here
+-- Phases split a period in named date ranges (teaching, revision, exams...).
+CREATE TABLE phases (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    period_id    INTEGER NOT NULL,
+    name  TEXT NOT NULL,
+    start_date   DATE NOT NULL,
+    end_date   DATE NOT NULL,
+    FOREIGN KEY (period_id) REFERENCES periods
+);
+-- A goal with a phase only applies during that phase.
+ALTER TABLE goals ADD COLUMN phase_id INTEGER REFERENCES phases;
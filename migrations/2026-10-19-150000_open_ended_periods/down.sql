@@ -0,0 +1,20 @@
+-- This file should undo anything in `up.sql`
+-- Open-ended periods are closed on the last day with entries (or their first day if there isn't any).
+PRAGMA foreign_keys=off;
+
+CREATE TABLE periods_new (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    initial_date    DATE NOT NULL,
+    final_date   DATE NOT NULL,
+    description TEXT NOT NULL,
+    grading_scale TEXT NOT NULL DEFAULT '0-10'
+);
+
+INSERT INTO periods_new (id, initial_date, final_date, description, grading_scale)
+SELECT id, initial_date,
+       COALESCE(final_date, (SELECT MAX(entry.date) FROM entry JOIN subjects ON subjects.id = entry.subject_id
+                             WHERE subjects.period_id = periods.id), initial_date),
+       description, grading_scale FROM periods;
+DROP TABLE periods;
+ALTER TABLE periods_new RENAME TO periods;
+PRAGMA foreign_keys=on;
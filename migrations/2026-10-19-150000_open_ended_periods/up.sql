@@ -0,0 +1,17 @@
+-- Your SQL goes here
+-- Periods without a final date are open-ended, so final_date becomes nullable.
+PRAGMA foreign_keys=off;
+
+CREATE TABLE periods_new (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    initial_date    DATE NOT NULL,
+    final_date   DATE,
+    description TEXT NOT NULL,
+    grading_scale TEXT NOT NULL DEFAULT '0-10'
+);
+
+INSERT INTO periods_new (id, initial_date, final_date, description, grading_scale)
+SELECT id, initial_date, final_date, description, grading_scale FROM periods;
+DROP TABLE periods;
+ALTER TABLE periods_new RENAME TO periods;
+PRAGMA foreign_keys=on;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE periods DROP COLUMN academic_year;
+ALTER TABLE periods DROP COLUMN program_id;
+DROP TABLE programs;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+-- Programs (degrees) group periods into academic years.
+CREATE TABLE programs (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name  TEXT NOT NULL,
+    required_credits   REAL NOT NULL
+);
+ALTER TABLE periods ADD COLUMN program_id INTEGER REFERENCES programs;
+ALTER TABLE periods ADD COLUMN academic_year INTEGER;
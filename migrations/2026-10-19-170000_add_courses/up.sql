@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Subjects of different periods with the same course_id are the same course (e.g.: a year-long subject).
+-- The course is identified by the id of the subject it started with.
+ALTER TABLE subjects ADD COLUMN course_id INTEGER;
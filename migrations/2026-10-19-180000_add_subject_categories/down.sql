@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE subjects DROP COLUMN parent_id;
+ALTER TABLE subjects DROP COLUMN category;
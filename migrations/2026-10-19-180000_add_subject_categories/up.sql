@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Subjects can be grouped by category (math, lab, electives...) and under a parent subject of the same period.
+ALTER TABLE subjects ADD COLUMN category TEXT;
+ALTER TABLE subjects ADD COLUMN parent_id INTEGER REFERENCES subjects;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+-- Color used to print the subject. Existing subjects get one from the palette, by id.
+ALTER TABLE subjects ADD COLUMN color TEXT;
+UPDATE subjects SET color = CASE id % 12
+    WHEN 0 THEN 'cyan'
+    WHEN 1 THEN 'magenta'
+    WHEN 2 THEN 'yellow'
+    WHEN 3 THEN 'green'
+    WHEN 4 THEN 'blue'
+    WHEN 5 THEN 'red'
+    WHEN 6 THEN 'bright cyan'
+    WHEN 7 THEN 'bright magenta'
+    WHEN 8 THEN 'bright yellow'
+    WHEN 9 THEN 'bright green'
+    WHEN 10 THEN 'bright blue'
+    ELSE 'bright red'
+END;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- Date since which the subject is archived (e.g.: it was dropped). NULL means it is taken.
+ALTER TABLE subjects ADD COLUMN archived_at DATE;
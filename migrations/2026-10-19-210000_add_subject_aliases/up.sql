@@ -0,0 +1,8 @@
+-- Your SQL goes here
+-- Other names a subject can be referred to by (besides its short name), unique in its period.
+CREATE TABLE subject_aliases (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    subject_id  INTEGER NOT NULL,
+    alias   TEXT NOT NULL,
+    FOREIGN KEY (subject_id) REFERENCES subjects
+);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Free-text note of the entry, and its tags (lowercase, without '#', separated by commas).
+ALTER TABLE entry ADD COLUMN note TEXT;
+ALTER TABLE entry ADD COLUMN tags TEXT;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE entry DROP COLUMN activity;
+DROP TABLE activity_types;
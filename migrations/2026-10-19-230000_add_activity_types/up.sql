@@ -0,0 +1,10 @@
+-- Your SQL goes here
+-- Kinds of activity the time of an entry was spent on (lectures, homework...).
+CREATE TABLE activity_types (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name    TEXT NOT NULL UNIQUE
+);
+INSERT INTO activity_types (name) VALUES ('study'), ('lecture'), ('homework'), ('revision'), ('lab');
+
+-- Entries are now unique per subject, day and activity type. Existing ones were study time.
+ALTER TABLE entry ADD COLUMN activity TEXT NOT NULL DEFAULT 'study';
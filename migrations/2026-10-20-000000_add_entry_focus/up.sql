@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- Focus rating (1 to 5) of the time of an entry, averaged by time when rated time is added to it.
+ALTER TABLE entry ADD COLUMN focus REAL;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+-- Pomodoros run with the pomodoro command. Interrupted ones are kept (with the minutes they lasted) to know how often
+-- focus is broken, but only the time of completed ones is logged as entries.
+CREATE TABLE pomodoros (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    subject_id  INTEGER NOT NULL REFERENCES subjects(id),
+    started_at  TIMESTAMP NOT NULL,
+    work_minutes    INTEGER NOT NULL,
+    focused_minutes INTEGER NOT NULL,
+    completed   BOOLEAN NOT NULL
+);
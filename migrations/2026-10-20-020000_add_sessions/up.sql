@@ -0,0 +1,12 @@
+-- Your SQL goes here
+-- Study sessions with a start time, so the time can be analyzed by the hour of the day. Entries keep the time of every
+-- day, and sessions only record when (some of) that time was studied.
+CREATE TABLE sessions (
+    id  INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    subject_id  INTEGER NOT NULL REFERENCES subjects(id),
+    started_at  TIMESTAMP NOT NULL,
+    minutes INTEGER NOT NULL
+);
+-- Completed pomodoros were logged with their start time.
+INSERT INTO sessions (subject_id, started_at, minutes)
+SELECT subject_id, started_at, focused_minutes FROM pomodoros WHERE completed;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+-- User settings, like the time of the day when a study day starts.
+CREATE TABLE settings (
+    name    TEXT PRIMARY KEY NOT NULL,
+    value   TEXT NOT NULL
+);
@@ -0,0 +1,40 @@
+//! Handles activity type creation.
+use crate::models::ActivityType;
+use crate::schema::activity_types::dsl::activity_types;
+use crate::schema::activity_types::name;
+use diesel::ExpressionMethods;
+use diesel::{insert_into, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Adds a new activity type. Names are single lowercase words, so they can be given to --type and used as csv
+/// headers.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `new_name` - Name of the activity type.
+pub fn add(conn: &mut SqliteConnection, new_name: &str) {
+    let new_name = new_name.trim().to_lowercase();
+    if new_name.is_empty()
+        || !new_name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+        eprintln!("An activity type must be a single word (letters, digits, '-' or '_')");
+        process::exit(1);
+    }
+    if ActivityType::from_name(conn, &new_name).is_some() {
+        eprintln!("There is already an activity type '{new_name}'");
+        process::exit(1);
+    }
+    match insert_into(activity_types)
+        .values(name.eq(&new_name))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!("Activity type '{new_name}' added succesfully");
+        }
+        Err(e) => {
+            eprintln!("Failed to insert activity type: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Handles activity type listing.
+use crate::format_hours_and_minutes;
+use crate::models::{ActivityType, DEFAULT_ACTIVITY};
+use diesel::SqliteConnection;
+
+/// Lists all activity types with the time logged to each of them.
+/// # Arguments
+/// * `conn` - Database connection.
+pub fn list(conn: &mut SqliteConnection) {
+    println!("Activity types:");
+    for a in ActivityType::fetch_all(conn) {
+        let time = a.total_time(conn);
+        match a.name == DEFAULT_ACTIVITY {
+            true => println!("\t * {a} (default): {}", format_hours_and_minutes(time)),
+            false => println!("\t * {a}: {}", format_hours_and_minutes(time)),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Handles commands related to activity types, the kind of activity the time of an entry is spent on (study,
+//! lecture, homework...).
+
+mod add;
+mod list;
+mod remove;
+mod usage;
+
+use crate::commands::activity::usage::display_bad_usage;
+use crate::debug_println;
+use crate::interpreter::request_confirmation;
+use crate::models::ActivityType;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Interprets activity subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    match option.trim() {
+        "add" => match args.first() {
+            Some(new_name) => add::add(conn, new_name),
+            None => {
+                display_bad_usage();
+                process::exit(1);
+            }
+        },
+        "list" => {
+            list::list(conn);
+        }
+        "remove" => {
+            let activity_type = match args.first() {
+                Some(name_arg) => match ActivityType::from_name(conn, name_arg) {
+                    Some(a) => a,
+                    None => {
+                        eprintln!("There is no activity type '{name_arg}'");
+                        process::exit(1);
+                    }
+                },
+                None => {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+            };
+            if !args.contains(&"--confirm".to_string()) {
+                println!("{}", activity_type);
+                request_confirmation("Are you sure you want to remove the activity type? [Y/N]");
+            }
+            remove::remove(conn, &activity_type);
+        }
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
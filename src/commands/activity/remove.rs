@@ -0,0 +1,35 @@
+//! Handles activity type removal.
+use crate::models::{ActivityType, DEFAULT_ACTIVITY};
+use crate::schema::activity_types::dsl::activity_types;
+use crate::schema::activity_types::id;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Removes an activity type. The default one and the ones used by entries can't be removed.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `activity_type` - Activity type to remove.
+pub fn remove(conn: &mut SqliteConnection, activity_type: &ActivityType) {
+    if activity_type.name == DEFAULT_ACTIVITY {
+        eprintln!("The default activity type can't be removed");
+        process::exit(1);
+    }
+    let used = activity_type.count_entries(conn);
+    if used > 0 {
+        eprintln!(
+            "{used} entries are of type '{activity_type}'. Change their type with `entry edit` first."
+        );
+        process::exit(1);
+    }
+    match delete(activity_types.filter(id.eq(activity_type.id))).execute(conn) {
+        Ok(_) => {
+            println!("Activity type removed succesfully");
+        }
+        Err(e) => {
+            eprintln!("Error deleting activity type: {e}");
+            process::exit(1);
+        }
+    }
+}
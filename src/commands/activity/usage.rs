@@ -0,0 +1,13 @@
+//! Activity module usage
+
+/// Displays the bad usage message of the activity module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} activity ...:\n
+        - add (name) : Adds an activity type (a single word, like tutoring) to use with --type when logging time.
+        - list : Lists all the activity types, with the time logged to each of them.
+        - remove (name) [--confirm] : Removes an activity type no entry uses. The default type, study, can't be removed.
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
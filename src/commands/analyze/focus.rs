@@ -0,0 +1,102 @@
+//! Handles the focus analysis of a plan: raw and effective study time, and average focus per subject and weekday.
+use crate::commands::analyze::usage::display_bad_usage;
+use crate::commands::plan::get_plan_arg;
+use crate::format_hours_and_minutes;
+use crate::interpreter::detect_unknown_arg;
+use crate::models::{Entry, Period, MAX_FOCUS};
+use diesel::internal::derives::multiconnection::chrono::{Datelike, Weekday};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Weekdays, in the order they are listed.
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Describes the time of some entries: raw time, rated time, average focus and effective time.
+/// # Arguments
+/// * `entries` - Entries to describe.
+fn describe(entries: &[&Entry]) -> String {
+    let raw: i32 = entries.iter().map(|e| e.dedicated_time).sum();
+    match Entry::average_focus(entries.iter().copied()) {
+        Some(focus) => {
+            let rated: i32 = entries
+                .iter()
+                .filter(|e| e.focus.is_some())
+                .map(|e| e.dedicated_time)
+                .sum();
+            let effective: f64 = entries.iter().map(|e| e.effective_minutes()).sum();
+            format!(
+                "{} ({} rated), average focus {focus:.1}/{MAX_FOCUS}, {} effective",
+                format_hours_and_minutes(raw),
+                format_hours_and_minutes(rated),
+                format_hours_and_minutes(effective.round() as i32)
+            )
+        }
+        None => format!("{}, not rated", format_hours_and_minutes(raw)),
+    }
+}
+
+/// Analyzes the focus of the study time of a plan, based on program args.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `args` - Remaining program arguments.
+pub fn analyze_focus(conn: &mut SqliteConnection, args: &mut Vec<String>) {
+    let plan_id = get_plan_arg(args, conn);
+    if let Some(o) = detect_unknown_arg(args, &vec![], "--") {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let period = match Period::from_id(conn, plan_id) {
+        Some(period) => period,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    };
+    let subject_list = period.fetch_subjects(conn);
+    let ids: Vec<i32> = subject_list.iter().map(|s| s.id).collect();
+    let entries = Entry::fetch_by_subjects(conn, &ids, (None, None));
+    println!("Plan: {} (ID:{})", period.description, period.id);
+    if Entry::average_focus(&entries).is_none() {
+        println!(
+            "No rated time yet. Rate the focus of your sessions with `add ... --focus (1-5)`."
+        );
+        return;
+    }
+    println!("\tBy subject:");
+    for s in &subject_list {
+        let of_subject: Vec<&Entry> = entries.iter().filter(|e| e.subject_id == s.id).collect();
+        if !of_subject.is_empty() {
+            println!("\t * {}: {}", s.paint(&s.name), describe(&of_subject));
+        }
+    }
+    println!("\tBy weekday:");
+    let mut averages: Vec<(Weekday, f64)> = Vec::new();
+    for day in WEEKDAYS {
+        let of_day: Vec<&Entry> = entries.iter().filter(|e| e.date.weekday() == day).collect();
+        if !of_day.is_empty() {
+            println!("\t * {day}: {}", describe(&of_day));
+        }
+        if let Some(focus) = Entry::average_focus(of_day) {
+            averages.push((day, focus));
+        }
+    }
+    averages.sort_by(|a, b| a.1.total_cmp(&b.1));
+    if let (Some(worst), Some(best)) = (averages.first(), averages.last()) {
+        if averages.len() > 1 {
+            println!();
+            println!(
+                "\tYou focus best on {} ({:.1}) and worst on {} ({:.1}).",
+                best.0, best.1, worst.0, worst.1
+            );
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Handles the effort vs. grade analysis of the marked subjects.
+use crate::commands::analyze::usage::display_bad_usage;
+use crate::commands::analyze::{linear_regression, mean, pearson};
+use crate::interpreter::{detect_unknown_arg, get_specific_arg};
+use crate::models::{GradingScale, Period, Subject};
+use crate::{format_hours_and_minutes, get_terminal_width, today};
+use colored::Colorize;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Number of rows of the scatter plot.
+const PLOT_HEIGHT: usize = 15;
+/// Number of columns taken by the vertical axis of the scatter plot.
+const AXIS_WIDTH: usize = 8;
+/// Minimum number of marked subjects needed to analyze them.
+const MIN_SUBJECTS: usize = 3;
+/// Number of standard deviations of the residuals from which a subject is flagged as an outlier.
+const OUTLIER_THRESHOLD: f64 = 1.5;
+
+/// Study time and mark of a subject.
+struct Sample {
+    subject: Subject,
+    /// Total study time, in hours.
+    hours: f64,
+    /// Average study time per week of the period, in hours.
+    weekly_hours: f64,
+    /// Mark, converted to the output grading scale.
+    mark: f64,
+}
+
+/// Gets the study time and mark of every marked subject.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `scale` - Grading scale to convert the marks to.
+fn get_samples(conn: &mut SqliteConnection, scale: GradingScale) -> Vec<Sample> {
+    let today = today();
+    let mut samples = Vec::new();
+    for period in Period::fetch_all_plans(conn) {
+        let days = (period.end_date().min(today) - period.initial_date).num_days() + 1;
+        let weeks = (days as f64 / 7.0).max(1.0);
+        for subject in period.fetch_subjects(conn) {
+            if let Some(score) = subject.final_score {
+                let hours = subject.total_dedicated_time(conn) as f64 / 60.0;
+                samples.push(Sample {
+                    hours,
+                    weekly_hours: hours / weeks,
+                    mark: period.scale().convert(score, scale) as f64,
+                    subject,
+                });
+            }
+        }
+    }
+    samples
+}
+
+/// Describes the strength of a correlation coefficient.
+/// # Arguments
+/// * `r` - Correlation coefficient.
+fn describe_correlation(r: Option<f64>) -> String {
+    match r {
+        Some(r) => {
+            let strength = match r.abs() {
+                a if a >= 0.7 => "strong",
+                a if a >= 0.4 => "moderate",
+                a if a >= 0.2 => "weak",
+                _ => "no",
+            };
+            let direction = match (strength, r > 0.0) {
+                ("no", _) => "",
+                (_, true) => " positive",
+                (_, false) => " negative",
+            };
+            format!("{:.2} ({strength}{direction} correlation)", r)
+        }
+        None => String::from("- (not enough variation)"),
+    }
+}
+
+/// Prints a scatter plot of the study time (horizontal axis) and the mark (vertical axis) of every subject, where
+/// every subject is drawn with the first letter of its short name, along with the regression line.
+/// # Arguments
+/// * `samples` - Subjects to draw.
+/// * `regression` - (intercept, slope) of the regression line, if any.
+/// * `max_mark` - Maximum mark of the grading scale.
+fn print_scatter_plot(samples: &[Sample], regression: Option<(f64, f64)>, max_mark: f64) {
+    let width = get_terminal_width().saturating_sub(AXIS_WIDTH + 2).max(20);
+    let max_hours = samples.iter().map(|s| s.hours).fold(0.0, f64::max).max(1.0);
+    let row_of = |mark: f64| ((1.0 - mark / max_mark) * (PLOT_HEIGHT - 1) as f64).round() as usize;
+    let mut grid = vec![vec![' '; width]; PLOT_HEIGHT];
+    if let Some((intercept, slope)) = regression {
+        let line = (0..width)
+            .map(|column| intercept + slope * column as f64 / (width - 1) as f64 * max_hours)
+            .enumerate()
+            .filter(|(_, mark)| (0.0..=max_mark).contains(mark))
+            .collect::<Vec<(usize, f64)>>();
+        for (column, mark) in line {
+            grid[row_of(mark)][column] = '·';
+        }
+    }
+    for s in samples {
+        let column = (s.hours / max_hours * (width - 1) as f64).round() as usize;
+        let row = row_of(s.mark.clamp(0.0, max_mark));
+        grid[row][column] = match grid[row][column] {
+            c if c.is_alphanumeric() || c == '*' => '*',
+            _ => s
+                .subject
+                .short_name
+                .chars()
+                .next()
+                .unwrap_or('*')
+                .to_ascii_uppercase(),
+        };
+    }
+    for (i, row) in grid.iter().enumerate() {
+        let label = match i {
+            0 => format!("{:.1}", max_mark),
+            i if i == PLOT_HEIGHT - 1 => String::from("0"),
+            i if i == PLOT_HEIGHT / 2 => format!("{:.1}", max_mark / 2.0),
+            _ => String::new(),
+        };
+        println!(
+            "{:>width$} |{}",
+            label,
+            row.iter().collect::<String>(),
+            width = AXIS_WIDTH - 1
+        );
+    }
+    println!("{}+{}", " ".repeat(AXIS_WIDTH), "-".repeat(width));
+    let max_label = format!("{:.0}h", max_hours);
+    println!(
+        "{}0h{}{}",
+        " ".repeat(AXIS_WIDTH),
+        " ".repeat(width.saturating_sub(2 + max_label.len())),
+        max_label
+    );
+    println!(
+        "{}(study time vs. mark, * = several subjects)",
+        " ".repeat(AXIS_WIDTH)
+    );
+}
+
+/// Analyzes the relation between the study time and the mark of every marked subject, based on program args.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `args` - Remaining program arguments.
+pub fn analyze_grades(conn: &mut SqliteConnection, args: &mut [String]) {
+    if let Some(o) = detect_unknown_arg(&args.to_vec(), &vec!["--scale"], "--") {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let scale = match get_specific_arg(args, "--scale") {
+        Some(s) => match s.parse::<GradingScale>() {
+            Ok(scale) => scale,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        },
+        None => GradingScale::Ten,
+    };
+    let samples = get_samples(conn, scale);
+    if samples.len() < MIN_SUBJECTS {
+        println!(
+            "At least {MIN_SUBJECTS} marked subjects are needed to analyze them ({} found).",
+            samples.len()
+        );
+        return;
+    }
+    println!("Marked subjects (marks in the {scale} scale):");
+    for s in &samples {
+        println!(
+            "\t * {}: {}, {}/week, mark {}",
+            s.subject,
+            format_hours_and_minutes((s.hours * 60.0).round() as i32),
+            format_hours_and_minutes((s.weekly_hours * 60.0).round() as i32),
+            scale.format_score(s.mark as f32)
+        );
+    }
+    let hours = samples.iter().map(|s| s.hours).collect::<Vec<f64>>();
+    let weekly_hours = samples.iter().map(|s| s.weekly_hours).collect::<Vec<f64>>();
+    let marks = samples.iter().map(|s| s.mark).collect::<Vec<f64>>();
+    println!();
+    println!(
+        "\tTotal study time vs. mark: {}",
+        describe_correlation(pearson(&hours, &marks))
+    );
+    println!(
+        "\tWeekly study time vs. mark: {}",
+        describe_correlation(pearson(&weekly_hours, &marks))
+    );
+    let regression = linear_regression(&hours, &marks);
+    if let Some((intercept, slope)) = regression {
+        println!(
+            "\tLinear regression: mark = {:.2} + {:.3} * hours (every 10 hours of study are {:+.2} points)",
+            intercept,
+            slope,
+            slope * 10.0
+        );
+    }
+    println!();
+    print_scatter_plot(&samples, regression, scale.max() as f64);
+
+    if let Some((intercept, slope)) = regression {
+        let residuals = samples
+            .iter()
+            .map(|s| s.mark - (intercept + slope * s.hours))
+            .collect::<Vec<f64>>();
+        let deviation =
+            (residuals.iter().map(|r| r.powi(2)).sum::<f64>() / residuals.len() as f64).sqrt();
+        let mean_hours = mean(&hours);
+        let outliers = samples
+            .iter()
+            .zip(&residuals)
+            .filter(|(_, r)| deviation > 0.0 && r.abs() > OUTLIER_THRESHOLD * deviation)
+            .collect::<Vec<(&Sample, &f64)>>();
+        println!();
+        if outliers.is_empty() {
+            println!("\tNo subject is far from what its study time predicts.");
+        }
+        for (s, r) in outliers {
+            let description = match (*r > 0.0, s.hours > mean_hours) {
+                (false, true) => "a lot of effort, low mark".red(),
+                (false, false) => "lower mark than expected".red(),
+                (true, false) => "little effort, high mark".green(),
+                (true, true) => "higher mark than expected".green(),
+            };
+            println!(
+                "\t * {}: {} (expected {:.2}, got {:.2})",
+                s.subject.name,
+                description,
+                s.mark - r,
+                s.mark
+            );
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Handles the time-of-day analysis of the study sessions: when the study time is spent, by hour of the day and
+//! weekday, the peak hours of every subject and how they change from a plan to another.
+use crate::commands::analyze::usage::display_bad_usage;
+use crate::commands::chart::print_bar_chart;
+use crate::commands::heatmap::{cell, intensity_level};
+use crate::commands::plan::get_plan_arg;
+use crate::commands::status::WEEKDAY_START;
+use crate::interpreter::detect_unknown_arg;
+use crate::models::{Entry, Period, Session};
+use crate::{format_hours_and_minutes, study_day};
+use colored::{ColoredString, Colorize};
+use diesel::internal::derives::multiconnection::chrono::{Datelike, Timelike};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Parts of the day, as (name, first hour, last hour (excluded)) tuples.
+const PARTS_OF_DAY: [(&str, usize, usize); 4] = [
+    ("night", 0, 6),
+    ("morning", 6, 12),
+    ("afternoon", 12, 18),
+    ("evening", 18, 24),
+];
+/// Number of columns taken by each cell of the grid (glyph and separation).
+const CELL_WIDTH: usize = 2;
+/// Number of columns taken by the weekday labels of the grid.
+const LABEL_WIDTH: usize = 4;
+/// Hours between the labels of the grid.
+const LABEL_EVERY: usize = 3;
+
+/// Study time of some sessions by weekday (from the first day of the week) and hour of the day.
+type Grid = [[i32; 24]; 7];
+
+/// Gets the study time of some sessions by weekday and hour. Sessions are split by the hours they span, and every
+/// hour is counted in the weekday of its study day (e.g.: 01:00 on Tuesday is Monday, when days start at 04:00).
+/// # Arguments
+/// * `sessions` - Sessions to add up.
+fn get_grid<'a>(sessions: impl IntoIterator<Item = &'a Session>) -> Grid {
+    let mut grid = [[0; 24]; 7];
+    for s in sessions {
+        for (hour, minutes) in s.split_by_hour() {
+            let day = study_day(hour).weekday().days_since(WEEKDAY_START) as usize;
+            grid[day][hour.hour() as usize] += minutes;
+        }
+    }
+    grid
+}
+
+/// Gets the study time of every hour of the day of a grid.
+/// # Arguments
+/// * `grid` - Study time by weekday and hour.
+fn by_hour(grid: &Grid) -> [i32; 24] {
+    let mut hours = [0; 24];
+    for row in grid {
+        for (hour, time) in row.iter().enumerate() {
+            hours[hour] += time;
+        }
+    }
+    hours
+}
+
+/// Gets the hour of the day with the most study time.
+/// # Arguments
+/// * `hours` - Study time of every hour of the day.
+fn peak_hour(hours: &[i32; 24]) -> usize {
+    (0..24).max_by_key(|h| (hours[*h], -(*h as i32))).unwrap()
+}
+
+/// Gets the part of the day with the most study time, with its share of the total time.
+/// # Arguments
+/// * `hours` - Study time of every hour of the day.
+fn main_part_of_day(hours: &[i32; 24]) -> (&'static str, f64) {
+    let total = hours.iter().sum::<i32>().max(1);
+    PARTS_OF_DAY
+        .iter()
+        .map(|(name, from, to)| (*name, hours[*from..*to].iter().sum::<i32>()))
+        .max_by_key(|(_, time)| *time)
+        .map(|(name, time)| (name, time as f64 * 100.0 / total as f64))
+        .unwrap()
+}
+
+/// Prints the study time of every hour of the day (from the first to the last hour with time) as a bar chart.
+/// # Arguments
+/// * `hours` - Study time of every hour of the day.
+fn print_hours_chart(hours: &[i32; 24]) {
+    let first = hours.iter().position(|t| *t > 0).unwrap_or(0);
+    let last = hours.iter().rposition(|t| *t > 0).unwrap_or(23);
+    let times: Vec<(ColoredString, i32)> = (first..=last)
+        .map(|h| (format!("{h:02}:00").normal(), hours[h]))
+        .collect();
+    print_bar_chart(&times);
+}
+
+/// Prints a heat grid of the study time, where every row is a weekday and every column an hour of the day.
+/// # Arguments
+/// * `grid` - Study time by weekday and hour.
+fn print_grid(grid: &Grid) {
+    let max = grid.iter().flatten().copied().max().unwrap_or(0);
+    let mut labels = " ".repeat(LABEL_WIDTH);
+    for hour in (0..24).step_by(LABEL_EVERY) {
+        labels.push_str(&format!(
+            "{:<width$}",
+            hour,
+            width = CELL_WIDTH * LABEL_EVERY
+        ));
+    }
+    println!("\t{}", labels.trim_end());
+    let mut weekday = WEEKDAY_START;
+    for row in grid {
+        print!("\t{:<width$}", weekday.to_string(), width = LABEL_WIDTH);
+        for time in row {
+            print!(
+                "{}{}",
+                cell(intensity_level(*time, max)),
+                " ".repeat(CELL_WIDTH - 1)
+            );
+        }
+        println!();
+        weekday = weekday.succ();
+    }
+    println!();
+    print!("\t{}Less ", " ".repeat(LABEL_WIDTH));
+    for level in 0..5 {
+        print!("{} ", cell(level));
+    }
+    println!("More (max: {} in an hour)", format_hours_and_minutes(max));
+}
+
+/// Analyzes when the study time of a plan is spent, based on program args.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `args` - Remaining program arguments.
+pub fn analyze_hours(conn: &mut SqliteConnection, args: &mut Vec<String>) {
+    let plan_id = get_plan_arg(args, conn);
+    if let Some(o) = detect_unknown_arg(args, &vec![], "--") {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let period = match Period::from_id(conn, plan_id) {
+        Some(period) => period,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    };
+    let subject_list = period.fetch_subjects(conn);
+    let ids: Vec<i32> = subject_list.iter().map(|s| s.id).collect();
+    let sessions = Session::fetch_by_subjects(conn, &ids);
+    println!("Plan: {} (ID:{})", period.description, period.id);
+    if sessions.is_empty() {
+        println!("No sessions with a start time yet. Log them with `add ... --at HH:MM` or with `pomodoro`.");
+        return;
+    }
+    let timed: i32 = sessions.iter().map(|s| s.minutes).sum();
+    let logged: i32 = Entry::fetch_by_subjects(conn, &ids, (None, None))
+        .iter()
+        .map(|e| e.dedicated_time)
+        .sum();
+    println!(
+        "{} of the {} logged have a start time.\n",
+        format_hours_and_minutes(timed),
+        format_hours_and_minutes(logged)
+    );
+    let grid = get_grid(&sessions);
+    let hours = by_hour(&grid);
+    println!("\tStudy time by hour of the day:");
+    print_hours_chart(&hours);
+    println!();
+    println!("\tStudy time by weekday and hour:");
+    print_grid(&grid);
+    println!();
+    println!("\tPeak hours by subject:");
+    for s in &subject_list {
+        let subject_hours = by_hour(&get_grid(sessions.iter().filter(|x| x.subject_id == s.id)));
+        let total: i32 = subject_hours.iter().sum();
+        if total > 0 {
+            let peak = peak_hour(&subject_hours);
+            let (part, share) = main_part_of_day(&subject_hours);
+            println!(
+                "\t * {}: {peak:02}:00-{:02}:00 ({}, {:.1}% of its time), mostly in the {part} ({share:.1}%)",
+                s.paint(&s.name),
+                (peak + 1) % 24,
+                format_hours_and_minutes(subject_hours[peak]),
+                subject_hours[peak] as f64 * 100.0 / total as f64
+            );
+        }
+    }
+    let (part, share) = main_part_of_day(&hours);
+    println!();
+    println!("\tYou study mostly in the {part}: {share:.1}% of your time.");
+
+    // The plans are compared when more than one has sessions.
+    let mut plans: Vec<(Period, [i32; 24])> = Vec::new();
+    for p in Period::fetch_all_plans(conn) {
+        let ids: Vec<i32> = p.fetch_subjects(conn).iter().map(|s| s.id).collect();
+        let plan_hours = by_hour(&get_grid(&Session::fetch_by_subjects(conn, &ids)));
+        if plan_hours.iter().sum::<i32>() > 0 {
+            plans.push((p, plan_hours));
+        }
+    }
+    if plans.len() > 1 {
+        plans.sort_by_key(|(p, _)| p.initial_date);
+        println!();
+        println!("\tAcross plans:");
+        for (p, plan_hours) in plans {
+            let (part, share) = main_part_of_day(&plan_hours);
+            println!(
+                "\t * {} (ID:{}): peak at {:02}:00, mostly in the {part} ({share:.1}%)",
+                p.description,
+                p.id,
+                peak_hour(&plan_hours)
+            );
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! # Analysis of the study data.
+//! This module handles the analysis commands, which relate the study time with other data without leaving the tool:
+//! * **grades**: Effort vs. grade analysis of the marked subjects.
+//! * **focus**: Effective study time and average focus per subject and weekday.
+//! * **hours**: Distribution of the study time by hour of the day and weekday.
+
+mod focus;
+mod grades;
+mod hours;
+mod usage;
+
+use crate::commands::analyze::usage::display_bad_usage;
+use crate::debug_println;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Gets the mean of a list of values.
+/// # Arguments
+/// * `values` - Values.
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len().max(1) as f64
+}
+
+/// Gets the Pearson correlation coefficient between two lists of values. Returns None if any of them is constant.
+/// # Arguments
+/// * `x` - First list of values.
+/// * `y` - Second list of values.
+fn pearson(x: &[f64], y: &[f64]) -> Option<f64> {
+    let (mx, my) = (mean(x), mean(y));
+    let cov = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| (a - mx) * (b - my))
+        .sum::<f64>();
+    let sx = x.iter().map(|a| (a - mx).powi(2)).sum::<f64>().sqrt();
+    let sy = y.iter().map(|b| (b - my).powi(2)).sum::<f64>().sqrt();
+    if sx == 0.0 || sy == 0.0 {
+        None
+    } else {
+        Some(cov / (sx * sy))
+    }
+}
+
+/// Fits a simple linear regression `y = intercept + slope * x` by least squares. Returns None if `x` is constant.
+/// # Arguments
+/// * `x` - Independent values.
+/// * `y` - Dependent values.
+fn linear_regression(x: &[f64], y: &[f64]) -> Option<(f64, f64)> {
+    let (mx, my) = (mean(x), mean(y));
+    let sxx = x.iter().map(|a| (a - mx).powi(2)).sum::<f64>();
+    if sxx == 0.0 {
+        return None;
+    }
+    let slope = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| (a - mx) * (b - my))
+        .sum::<f64>()
+        / sxx;
+    Some((my - slope * mx, slope))
+}
+
+/// Interprets analyze subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    match option.trim() {
+        "grades" => grades::analyze_grades(conn, args),
+        "focus" => focus::analyze_focus(conn, args),
+        "hours" => hours::analyze_hours(conn, args),
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
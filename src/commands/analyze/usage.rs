@@ -0,0 +1,16 @@
+//! Analyze module usage
+
+/// Displays the bad usage message of the analyze module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} analyze ...:\n
+        - grades [--scale (0-10/0-20/A-F/4.0)]: Correlates the study time of every marked subject with its mark, with a scatter plot\n\
+          and a linear regression, and flags the subjects whose mark is far from what their study time predicts.\n\
+        - focus [--plan (plan id)]: Shows the raw and effective study time (weighted by focus) and the average focus of every\n\
+          subject and weekday.\n\
+        - hours [--plan (plan id)]: Shows when the sessions with a start time (logged with `add --at` or `pomodoro`) were studied: by\n\
+          hour of the day, in a weekday and hour grid, the peak hours of every subject and how they change across plans.\n\
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
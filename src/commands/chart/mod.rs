@@ -0,0 +1,221 @@
+//! # Charts of study time.
+//! The chart command draws, sized to the terminal width:
+//! * **Bar chart**: A horizontal bar for every subject, with the time dedicated to it and its share of the total.
+//! * **Sparklines**: A sparkline for every subject with the time dedicated to it in each of the last weeks.
+//!
+//! Both charts are also used by the status command when the `--chart` option is given.
+
+mod usage;
+
+use crate::commands::chart::usage::display_bad_usage;
+use crate::commands::plan::get_plan_arg;
+use crate::commands::status::WEEKDAY_START;
+use crate::interpreter::{detect_unknown_arg, get_specific_arg};
+use crate::models::{GroupBy, Period, Subject};
+use crate::{format_hours_and_minutes, get_terminal_width, today};
+use colored::{ColoredString, Colorize};
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, TimeDelta};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Number of weeks displayed in the sparklines if no other number is specified.
+pub const DEFAULT_SPARKLINE_WEEKS: usize = 8;
+/// Blocks used to draw the fractional part of a bar, in eighths.
+const PARTIAL_BLOCKS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+/// Blocks used to draw sparklines, from lowest to highest.
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Number of columns taken by the indentation of the charts.
+const INDENT_WIDTH: usize = 8;
+
+/// Builds a horizontal bar proportional to the given value.
+/// # Arguments
+/// * `value` - Value to draw.
+/// * `max` - Value that takes the whole width.
+/// * `width` - Maximum width of the bar, in columns.
+fn bar(value: i32, max: i32, width: usize) -> String {
+    if max <= 0 || value <= 0 {
+        return String::new();
+    }
+    let eighths = (value as i64 * width as i64 * 8 / max as i64) as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if !eighths.is_multiple_of(8) {
+        bar.push(PARTIAL_BLOCKS[eighths % 8 - 1]);
+    }
+    bar
+}
+
+/// Builds a sparkline of the given values, where every value is one character.
+/// # Arguments
+/// * `values` - Values to draw.
+/// * `max` - Value drawn with the highest block.
+fn sparkline(values: &[i32], max: i32) -> String {
+    values
+        .iter()
+        .map(|v| {
+            if *v <= 0 || max <= 0 {
+                ' '
+            } else {
+                SPARKS[((*v as i64 * 8 - 1) / max as i64).clamp(0, 7) as usize]
+            }
+        })
+        .collect()
+}
+
+/// Prints a horizontal bar chart with the time dedicated to every subject (or group of subjects).
+/// # Arguments
+/// * `times` - List of (subject short name or group name, dedicated time) tuples.
+pub(crate) fn print_bar_chart(times: &[(ColoredString, i32)]) {
+    let total = times.iter().map(|(_, t)| t).sum::<i32>();
+    if total == 0 {
+        println!("\tThere is no study time to chart yet.");
+        return;
+    }
+    let max = times.iter().map(|(_, t)| *t).max().unwrap_or(0);
+    let name_width = times
+        .iter()
+        .map(|(s, _)| s.input.chars().count())
+        .max()
+        .unwrap_or(0);
+    let labels = times
+        .iter()
+        .map(|(_, t)| {
+            format!(
+                "{} ({:.1}%)",
+                format_hours_and_minutes(*t),
+                *t as f64 * 100.0 / total as f64
+            )
+        })
+        .collect::<Vec<String>>();
+    let label_width = labels.iter().map(|l| l.len()).max().unwrap_or(0);
+    let bar_width = get_terminal_width()
+        .saturating_sub(INDENT_WIDTH + name_width + label_width + 3)
+        .max(10);
+    for ((name, time), label) in times.iter().zip(labels) {
+        let bar = bar(*time, max, bar_width);
+        let bar = match name.fgcolor {
+            Some(c) => bar.color(c),
+            None => bar.normal(),
+        };
+        println!("\t{:<name_width$} {:<bar_width$} {}", name, bar, label,);
+    }
+}
+
+/// Prints a sparkline for every subject of a period, with the time dedicated in each of the last weeks.
+/// Every sparkline uses the same scale, so that the subjects can be compared between them.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the subjects.
+/// * `last_day` - Day whose week is the last one to be drawn.
+/// * `weeks` - Maximum number of weeks to draw.
+pub(crate) fn print_sparklines(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    last_day: NaiveDate,
+    weeks: usize,
+) {
+    let first_week = period.initial_date.week(WEEKDAY_START).first_day();
+    let mut intervals: Vec<(NaiveDate, NaiveDate)> = Vec::new();
+    let mut i = last_day
+        .min(period.end_date())
+        .week(WEEKDAY_START)
+        .first_day();
+    while intervals.len() < weeks && i >= first_week {
+        intervals.insert(
+            0,
+            (
+                i.max(period.initial_date),
+                (i + TimeDelta::days(6)).min(period.end_date()),
+            ),
+        );
+        i -= TimeDelta::weeks(1);
+    }
+    // Subjects archived before the first week aren't drawn.
+    let subjects: Vec<Subject> = period
+        .fetch_subjects(conn)
+        .into_iter()
+        .filter(|s| intervals.first().is_none_or(|i| !s.is_archived(i.0)))
+        .collect();
+    let values = subjects
+        .iter()
+        .map(|s| {
+            intervals
+                .iter()
+                .map(|interval| s.total_dedicated_time_interval(conn, *interval))
+                .collect::<Vec<i32>>()
+        })
+        .collect::<Vec<Vec<i32>>>();
+    let max = values.iter().flatten().copied().max().unwrap_or(0);
+    let name_width = subjects
+        .iter()
+        .map(|s| s.short_name.chars().count())
+        .max()
+        .unwrap_or(0);
+    println!("\tLast {} weeks:", intervals.len());
+    for (subject, weekly) in subjects.iter().zip(values) {
+        println!(
+            "\t{:<name_width$} |{}| {}/week on average",
+            subject.paint(&subject.short_name),
+            subject.paint(&sparkline(&weekly, max)),
+            format_hours_and_minutes(weekly.iter().sum::<i32>() / (weekly.len().max(1) as i32)),
+        );
+    }
+}
+
+/// Displays the charts of a period, based on program args.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `args` - Remaining program arguments.
+pub fn display_charts(conn: &mut SqliteConnection, args: &mut Vec<String>) {
+    let plan_id = get_plan_arg(args, conn);
+    if let Some(o) = detect_unknown_arg(args, &vec!["--weeks", "--by"], "--") {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let weeks = match args.contains(&"--weeks".to_string()) {
+        true => match get_specific_arg(args, "--weeks").map(|w| w.parse::<usize>()) {
+            Some(Ok(w)) if w > 0 => w,
+            _ => {
+                eprintln!("The number of weeks must be a positive integer");
+                process::exit(1);
+            }
+        },
+        false => DEFAULT_SPARKLINE_WEEKS,
+    };
+    let by = match get_specific_arg(args, "--by").map(|b| b.parse::<GroupBy>()) {
+        Some(Ok(by)) => by,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+        None => GroupBy::Subject,
+    };
+    let period = match Period::from_id(conn, plan_id) {
+        Some(period) => period,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    };
+    let last_day = today().clamp(period.initial_date, period.end_date());
+    println!("Plan: {} (ID:{})", period.description, period.id);
+    let mut times: Vec<(Subject, i32)> = Vec::new();
+    for i in period.fetch_subjects(conn) {
+        let time = i.total_dedicated_time_interval(conn, (period.initial_date, last_day));
+        times.push((i, time));
+    }
+    match by {
+        GroupBy::Subject => println!("\tTime dedicated to every subject:"),
+        GroupBy::Category => println!("\tTime dedicated to every category:"),
+        GroupBy::Parent => println!("\tTime dedicated to every parent subject:"),
+        GroupBy::Tag => println!("\tTime dedicated to every tag:"),
+        GroupBy::Type => println!("\tTime dedicated to every activity type:"),
+    }
+    print_bar_chart(
+        &by.group(conn, &times, (period.initial_date, last_day), |s| {
+            s.short_name.clone()
+        }),
+    );
+    println!();
+    print_sparklines(conn, &period, last_day, weeks);
+}
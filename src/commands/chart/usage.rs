@@ -0,0 +1,13 @@
+//! Chart module usage
+
+/// Displays the bad usage message of the chart module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} chart [--plan (plan id)] [--weeks (number of weeks)] [--by (subject/category/parent/tag/type)]:\n
+        Displays a bar chart with the time dedicated to every subject of the current/specified plan, and a sparkline\n\
+        with the time dedicated to every subject in the last weeks (8 by default). With --by, the bar chart groups the\n\
+        subjects by category or parent subject.\n\
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
@@ -0,0 +1,96 @@
+//! Handles the settings of the program, like the time of the day when a study day starts.
+
+mod usage;
+
+use crate::commands::config::usage::display_bad_usage;
+use crate::debug_println;
+use crate::models::{Setting, DAY_START};
+use diesel::internal::derives::multiconnection::chrono::NaiveTime;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Settings that can be changed, with their default values.
+const SETTINGS: [(&str, &str); 1] = [(DAY_START, "00:00")];
+
+/// Validates the value of a setting, exiting if it isn't valid. Returns the value to store.
+/// # Arguments
+/// * `setting` - Name of the setting.
+/// * `new_value` - Value to validate.
+fn validate(setting: &str, new_value: &str) -> String {
+    match setting {
+        DAY_START => match NaiveTime::parse_from_str(new_value.trim(), "%H:%M") {
+            Ok(t) => t.format("%H:%M").to_string(),
+            Err(_) => {
+                eprintln!("The start of the day must be given as HH:MM (e.g.: 04:00)");
+                process::exit(1);
+            }
+        },
+        _ => new_value.to_string(),
+    }
+}
+
+/// Gets the name of a setting from an argument, exiting if there isn't any setting with that name.
+/// # Arguments
+/// * `setting_arg` - Setting argument.
+fn get_setting(setting_arg: Option<&String>) -> &'static str {
+    match setting_arg {
+        Some(arg) => match SETTINGS.iter().find(|(s, _)| *s == arg.trim()) {
+            Some((s, _)) => s,
+            None => {
+                eprintln!("There is no setting '{arg}'");
+                display_bad_usage();
+                process::exit(1);
+            }
+        },
+        None => {
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
+
+/// Interprets config subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    match option.trim() {
+        "list" => {
+            println!("Settings:");
+            for (setting, default) in SETTINGS {
+                match Setting::get(conn, setting) {
+                    Some(v) => println!("\t * {setting}: {v}"),
+                    None => println!("\t * {setting}: {default} (default)"),
+                }
+            }
+        }
+        "set" => {
+            let setting = get_setting(args.first());
+            let new_value = match args.get(1) {
+                Some(v) => validate(setting, v),
+                None => {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+            };
+            Setting::set(conn, setting, &new_value);
+            println!("{setting} set to {new_value}");
+        }
+        "reset" => {
+            let setting = get_setting(args.first());
+            Setting::unset(conn, setting);
+            println!("{setting} reset succesfully");
+        }
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
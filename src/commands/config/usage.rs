@@ -0,0 +1,16 @@
+//! Config module usage
+
+/// Displays the bad usage message of the config module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} config ...:\n
+        - list : Lists the settings and their values.
+        - set (setting) (value) : Changes a setting.
+        - reset (setting) : Sets a setting back to its default value.\n
+        Avaliable settings:
+        - day-start (HH:MM) : Time of the day when a study day starts (00:00 by default). Time logged before it, like a session\n\
+          until 01:00 when days start at 04:00, counts for the previous day.
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
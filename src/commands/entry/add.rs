@@ -1,7 +1,7 @@
 //! Add time command
 use crate::models::Subject;
 use crate::schema::entry::dsl::entry;
-use crate::schema::entry::{date, dedicated_time, subject_id};
+use crate::schema::entry::{activity, date, dedicated_time, subject_id};
 use diesel::dsl::insert_into;
 use diesel::internal::derives::multiconnection::chrono::NaiveDate;
 use diesel::{update, ExpressionMethods, QueryDsl};
@@ -13,44 +13,47 @@ use std::process;
 /// * `conn` - Database connection
 /// * `subject` - Subject studied.
 /// * `when` - Date when studied.
+/// * `activity_name` - Activity type of the time.
 /// * `amount_to_add` - Amount to add to the current time.
 pub fn add_time(
     conn: &mut SqliteConnection,
     subject: &Subject,
     when: NaiveDate,
+    activity_name: &str,
     amount_to_add: i32,
 ) {
-    let amount = subject.total_dedicated_time_day(when, conn) + amount_to_add;
-    // If there was no previous entries, it creates one.
-    if amount == amount_to_add {
-        match insert_into(entry)
+    let matching = entry
+        .filter(date.eq(when))
+        .filter(subject_id.eq(subject.id))
+        .filter(activity.eq(activity_name));
+    let exists = match matching.count().get_result::<i64>(conn) {
+        Ok(c) => c > 0,
+        Err(e) => {
+            eprintln!("Failed to fetch entry: {e}");
+            process::exit(1);
+        }
+    };
+    // If there was no previous entry, it creates one (unless there is no time to add).
+    let result = match exists {
+        true => {
+            let amount =
+                subject.activity_time_interval(conn, (when, when), activity_name) + amount_to_add;
+            update(matching)
+                .set(dedicated_time.eq(amount))
+                .execute(conn)
+        }
+        false if amount_to_add == 0 => Ok(0),
+        false => insert_into(entry)
             .values((
                 date.eq(when),
                 subject_id.eq(subject.id),
-                dedicated_time.eq(0),
+                activity.eq(activity_name),
+                dedicated_time.eq(amount_to_add),
             ))
-            .execute(conn)
-        {
-            Ok(_) => {}
-            Err(e) => {
-                eprintln!("Failed to insert entry: {e}");
-                process::exit(1);
-            }
-        }
-    }
-
-    match update(
-        entry
-            .filter(date.eq(when))
-            .filter(subject_id.eq(subject.id)),
-    )
-    .set(dedicated_time.eq(amount))
-    .execute(conn)
-    {
-        Ok(_) => {}
-        Err(e) => {
-            eprintln!("Failed to insert entry: {e}");
-            process::exit(1);
-        }
+            .execute(conn),
+    };
+    if let Err(e) = result {
+        eprintln!("Failed to insert entry: {e}");
+        process::exit(1);
     }
 }
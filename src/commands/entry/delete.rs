@@ -0,0 +1,22 @@
+//! Delete entry command
+use crate::schema::entry::dsl::entry;
+use crate::schema::entry::id;
+use diesel::{delete, ExpressionMethods, QueryDsl};
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Deletes an entry.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `entry_id` - Id of the entry.
+pub fn delete_entry(conn: &mut SqliteConnection, entry_id: i32) {
+    match delete(entry.filter(id.eq(entry_id))).execute(conn) {
+        Ok(_) => {
+            println!("Entry removed succesfully");
+        }
+        Err(e) => {
+            eprintln!("Failed to delete the entry: {e}");
+            process::exit(1);
+        }
+    }
+}
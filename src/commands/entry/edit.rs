@@ -0,0 +1,89 @@
+//! Edit entry command
+use crate::commands::entry::delete::delete_entry;
+use crate::models::{Entry, Period, Subject};
+use crate::schema::entry::dsl::entry;
+use crate::schema::entry::{activity, date, dedicated_time, focus, id, subject_id};
+use crate::{format_hours_and_minutes, FORMAT};
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::{update, ExpressionMethods, QueryDsl};
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Edits an entry, replacing its time, date, subject, activity type and focus. Setting its time to zero deletes it,
+/// as `set` does.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `old` - Entry to edit.
+/// * `minutes` - New dedicated time.
+/// * `when` - New date.
+/// * `subject` - New subject.
+/// * `activity_name` - New activity type.
+/// * `rating` - New focus rating (if any).
+pub fn edit_entry(
+    conn: &mut SqliteConnection,
+    old: &Entry,
+    minutes: i32,
+    when: NaiveDate,
+    subject: &Subject,
+    activity_name: &str,
+    rating: Option<f64>,
+) {
+    if minutes == 0 {
+        delete_entry(conn, old.id);
+        return;
+    }
+    match Period::from_id(conn, subject.period_id) {
+        Some(p) if p.contains(when) => {}
+        _ => {
+            eprintln!(
+                "{} is outside the period of {}",
+                when.format(FORMAT),
+                subject.name
+            );
+            process::exit(1);
+        }
+    }
+    // Entries already on an archived date can still be fixed, but not moved there.
+    if when != old.date || subject.id != old.subject_id {
+        subject.exit_if_archived(when);
+    }
+    // There is at most one entry per subject, day and activity type.
+    if let Some(other) = Entry::fetch_by_day(when, conn)
+        .into_iter()
+        .find(|e| e.subject_id == subject.id && e.activity == activity_name && e.id != old.id)
+    {
+        eprintln!(
+            "There is already an entry of {} ({}) on {} (ID:{}). Edit or delete that one instead.",
+            subject.short_name,
+            activity_name,
+            when.format(FORMAT),
+            other.id
+        );
+        process::exit(1);
+    }
+    match update(entry.filter(id.eq(old.id)))
+        .set((
+            dedicated_time.eq(minutes),
+            date.eq(when),
+            subject_id.eq(subject.id),
+            activity.eq(activity_name),
+            focus.eq(rating),
+        ))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!(
+                "Entry {} edited: {} of {} ({}) on {}",
+                old.id,
+                format_hours_and_minutes(minutes),
+                subject.short_name,
+                activity_name,
+                when.format(FORMAT)
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to edit the entry: {e}");
+            process::exit(1);
+        }
+    }
+}
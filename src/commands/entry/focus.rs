@@ -0,0 +1,54 @@
+//! Focus ratings of entries
+use crate::models::{Entry, Subject};
+use crate::schema::entry::dsl::entry;
+use crate::schema::entry::{focus, id};
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::{update, ExpressionMethods, QueryDsl};
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Rates the focus of the time just logged to the entry of a subject on a date. Since there is one entry per subject,
+/// day and activity type, the rating is averaged with the previous one of the entry, weighted by time. If the entry
+/// wasn't rated, the rating is given to all of its time.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject of the entry.
+/// * `when` - Date of the entry.
+/// * `activity_name` - Activity type of the entry.
+/// * `rating` - Focus rating (1 to 5) of the time just logged.
+/// * `previous_time` - Time the entry had before (its rating is kept for that time, if it had one).
+pub fn rate(
+    conn: &mut SqliteConnection,
+    subject: &Subject,
+    when: NaiveDate,
+    activity_name: &str,
+    rating: f64,
+    previous_time: i32,
+) {
+    let old = match Entry::fetch_by_day(when, conn)
+        .into_iter()
+        .find(|e| e.subject_id == subject.id && e.activity == activity_name)
+    {
+        Some(e) => e,
+        // There is nothing to rate if there is no time (e.g.: it was set to zero).
+        None => return,
+    };
+    let new_time = (old.dedicated_time - previous_time).max(0);
+    let merged = match old.focus {
+        Some(f) if previous_time > 0 && new_time > 0 => {
+            (f * previous_time as f64 + rating * new_time as f64)
+                / (previous_time + new_time) as f64
+        }
+        _ => rating,
+    };
+    match update(entry.filter(id.eq(old.id)))
+        .set(focus.eq(Some(merged)))
+        .execute(conn)
+    {
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Failed to save the focus of the entry: {e}");
+            process::exit(1);
+        }
+    }
+}
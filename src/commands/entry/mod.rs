@@ -1,18 +1,31 @@
 //! # Functionality of add, substract and set commands.
-//! This module handles the main data operations for study time registers.
-use crate::commands::entry::add::add_time;
+//! This module handles the main data operations for study time registers, and the edition and deletion of single
+//! entries (`entry edit` and `entry delete`).
+pub(crate) use crate::commands::entry::add::add_time;
+use crate::commands::entry::delete::delete_entry;
+use crate::commands::entry::edit::edit_entry;
+use crate::commands::entry::focus::rate;
+use crate::commands::entry::note::annotate;
 use crate::commands::entry::set::set_time;
 use crate::commands::entry::substract::subtract_time;
-use crate::commands::entry::usage::display_bad_usage;
-use crate::commands::subject::get_subject;
-use crate::format_hours_and_minutes;
-use crate::interpreter::parse_date;
-use crate::models::Period;
-use diesel::internal::derives::multiconnection::chrono::{Local, NaiveDate};
+use crate::commands::entry::usage::{display_bad_usage, display_entry_usage};
+use crate::commands::subject::{exit_no_subject, get_active_subject, get_subject};
+use crate::debug_println;
+use crate::interpreter::{
+    detect_unknown_arg, get_specific_arg, parse_date, parse_duration, request_confirmation,
+    take_specific_arg,
+};
+use crate::models::{ActivityType, Entry, Session, Subject, DEFAULT_ACTIVITY};
+use crate::{format_hours_and_minutes, moment_of, today, FORMAT};
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, NaiveTime};
 use diesel::SqliteConnection;
 use std::process;
 
 mod add;
+mod delete;
+mod edit;
+mod focus;
+mod note;
 mod set;
 mod substract;
 mod usage;
@@ -32,30 +45,58 @@ pub enum EntryMode {
 /// * `conn` - Database connection
 /// * `args` - Remaining program arguments
 /// * `mode` - Entry altering mode.
-pub fn time_setter(conn: &mut SqliteConnection, args: &mut [String], mode: EntryMode) {
-    let when: NaiveDate = match args.len() {
-        3 => parse_date(args.first().unwrap().clone().trim()),
-        _ => Local::now().naive_local().date(),
+pub fn time_setter(conn: &mut SqliteConnection, args: &mut Vec<String>, mode: EntryMode) {
+    let activity_name = match take_specific_arg(args, "--type") {
+        Some(t) => match ActivityType::from_name(conn, &t) {
+            Some(a) => a.name,
+            None => {
+                eprintln!("Unknown activity type '{t}'. Use `activity list` to see them, or `activity add` to add it.");
+                process::exit(1);
+            }
+        },
+        None => String::from(DEFAULT_ACTIVITY),
     };
-    if args.len() < 2 {
-        display_bad_usage();
+    let rating = take_specific_arg(args, "--focus").map(|f| parse_focus(&f));
+    if rating.is_some() && matches!(mode, EntryMode::Substract) {
+        eprintln!("Only the time added or set can be rated with --focus");
         process::exit(1);
     }
-    let plan_id = match Period::from_date(conn, &when) {
-        Some(plan) => plan.id,
-        None => {
-            eprintln!("There is no study plan ocurring on the current/specified date.");
-            process::exit(1);
+    let start = take_specific_arg(args, "--at").map(|t| {
+        match NaiveTime::parse_from_str(t.trim(), "%H:%M") {
+            Ok(t) => t,
+            Err(_) => {
+                eprintln!("The start time must be given as HH:MM (e.g.: --at 21:30)");
+                process::exit(1);
+            }
         }
-    };
-    let subject = match get_subject(args.first().unwrap(), conn, Some(plan_id)) {
-        Some(subject) => subject,
-        None => {
-            eprintln!("There is no subject with that id or short name");
-            process::exit(1);
+    });
+    if start.is_some() && !matches!(mode, EntryMode::Add) {
+        eprintln!("Only the time added can be given a start time with --at");
+        process::exit(1);
+    }
+    let new_note = take_specific_arg(args, "--note");
+    let mut new_tags: Vec<String> = Vec::new();
+    while args.contains(&"--tag".to_string()) {
+        match take_specific_arg(args, "--tag").and_then(|t| Entry::normalize_tag(&t)) {
+            Some(t) => new_tags.push(t),
+            None => {
+                eprintln!("Tags must be single words (e.g.: --tag exercises)");
+                process::exit(1);
+            }
         }
+    }
+    if args.len() < 2 || args.len() > 3 {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let when: NaiveDate = match args.len() {
+        3 => parse_date(args.first().unwrap().clone().trim()),
+        _ => today(),
     };
-    let amount = match args.get(1).unwrap().parse::<i32>() {
+    // The subject is looked up in every plan ocurring on the date, since plans may overlap.
+    let subject = get_active_subject(&args[args.len() - 2], conn, when);
+    subject.exit_if_archived(when);
+    let amount = match args.last().unwrap().parse::<i32>() {
         Ok(amount) => {
             if amount < 0 {
                 eprintln!("The amount of time can't be negative");
@@ -69,20 +110,189 @@ pub fn time_setter(conn: &mut SqliteConnection, args: &mut [String], mode: Entry
             process::exit(1);
         }
     };
+    let previous_time = match mode {
+        EntryMode::Add => subject.activity_time_interval(conn, (when, when), &activity_name),
+        _ => 0,
+    };
 
     match mode {
         EntryMode::Add => {
-            add_time(conn, &subject, when, amount);
+            add_time(conn, &subject, when, &activity_name, amount);
         }
         EntryMode::Substract => {
-            subtract_time(conn, &subject, when, amount);
+            subtract_time(conn, &subject, when, &activity_name, amount);
         }
         EntryMode::Set => {
-            set_time(conn, &subject, when, amount);
+            set_time(conn, &subject, when, &activity_name, amount);
         }
     }
+    if new_note.is_some() || !new_tags.is_empty() {
+        annotate(conn, &subject, when, &activity_name, new_note, &new_tags);
+    }
+    if !matches!(mode, EntryMode::Add) {
+        Session::trim(conn, &subject, when);
+    }
+    if let Some(t) = start.filter(|_| amount > 0) {
+        Session::record(conn, subject.id, moment_of(when, t), amount);
+    }
+    if let Some(r) = rating {
+        rate(conn, &subject, when, &activity_name, r, previous_time);
+    }
     println!(
         "Done! Current dedicated time today: {}",
         format_hours_and_minutes(subject.total_dedicated_time_day(when, conn))
     );
 }
+
+/// Parses a focus rating argument, exiting if it isn't a number from 1 to 5.
+/// # Arguments
+/// * `rating_arg` - Focus rating argument.
+fn parse_focus(rating_arg: &str) -> f64 {
+    match Entry::parse_focus(rating_arg) {
+        Some(f) => f,
+        None => {
+            eprintln!("The focus must be a number from 1 (distracted) to 5 (fully focused)");
+            process::exit(1);
+        }
+    }
+}
+
+/// Gets an entry from an id argument, exiting if there isn't any.
+/// # Arguments
+/// * `entry_arg` - Entry id argument.
+/// * `conn` - Database connection.
+fn get_entry(entry_arg: Option<&String>, conn: &mut SqliteConnection) -> Entry {
+    let entry_id = match entry_arg.map(|a| a.trim().parse::<i32>()) {
+        Some(Ok(i)) => i,
+        Some(Err(_)) => {
+            eprintln!("Failed to parse ID. Did you give a number?");
+            process::exit(1);
+        }
+        None => {
+            display_entry_usage();
+            process::exit(1);
+        }
+    };
+    match Entry::from_id(conn, entry_id) {
+        Some(e) => e,
+        None => {
+            eprintln!(
+                "There is no entry with id {entry_id}. Use `log` to see the ids of the entries."
+            );
+            process::exit(1);
+        }
+    }
+}
+
+/// Interprets entry subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
+    if args.is_empty() {
+        display_entry_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    match option.trim() {
+        "edit" => {
+            if let Some(o) = detect_unknown_arg(
+                args,
+                &vec!["--minutes", "--date", "--subject", "--type", "--focus"],
+                "--",
+            ) {
+                eprintln!("Unknown argument: {o}");
+                display_entry_usage();
+                process::exit(1);
+            }
+            let old = get_entry(args.first(), conn);
+            let minutes = match get_specific_arg(args, "--minutes") {
+                Some(m) => match parse_duration(&m) {
+                    Some(m) => Some(m),
+                    None => {
+                        eprintln!("The amount of time must be a positive or zero duration (e.g.: 90 or 1h30m)");
+                        process::exit(1);
+                    }
+                },
+                None => None,
+            };
+            let when = get_specific_arg(args, "--date").map(|d| parse_date(d.trim()));
+            let old_subject = match get_subject(&old.subject_id.to_string(), conn, None) {
+                Some(s) => s,
+                None => {
+                    eprintln!("Failed to fetch the subject of the entry");
+                    process::exit(1);
+                }
+            };
+            let subject: Option<Subject> = match get_specific_arg(args, "--subject") {
+                Some(s) => match get_subject(&s, conn, Some(old_subject.period_id)) {
+                    Some(subj) => Some(subj),
+                    None => exit_no_subject(&s, conn, Some(old_subject.period_id)),
+                },
+                None => None,
+            };
+            let activity_name = match get_specific_arg(args, "--type") {
+                Some(t) => match ActivityType::from_name(conn, &t) {
+                    Some(a) => Some(a.name),
+                    None => {
+                        eprintln!("Unknown activity type '{t}'. Use `activity list` to see them.");
+                        process::exit(1);
+                    }
+                },
+                None => None,
+            };
+            // The focus can be cleared with "none".
+            let rating = match get_specific_arg(args, "--focus") {
+                Some(f) if f.trim().eq_ignore_ascii_case("none") => Some(None),
+                Some(f) => Some(Some(parse_focus(&f))),
+                None => None,
+            };
+            if minutes.is_none()
+                && when.is_none()
+                && subject.is_none()
+                && activity_name.is_none()
+                && rating.is_none()
+            {
+                display_entry_usage();
+                process::exit(1);
+            }
+            edit_entry(
+                conn,
+                &old,
+                minutes.unwrap_or(old.dedicated_time),
+                when.unwrap_or(old.date),
+                subject.as_ref().unwrap_or(&old_subject),
+                activity_name.as_deref().unwrap_or(&old.activity),
+                rating.unwrap_or(old.focus),
+            );
+            Session::trim(conn, &old_subject, old.date);
+        }
+        "delete" => {
+            let old = get_entry(args.first(), conn);
+            let subject = get_subject(&old.subject_id.to_string(), conn, None);
+            if !args.contains(&"--confirm".to_string()) {
+                println!(
+                    "{} of {} ({}) on {}",
+                    format_hours_and_minutes(old.dedicated_time),
+                    subject
+                        .as_ref()
+                        .map(|s| s.short_name.as_str())
+                        .unwrap_or_default(),
+                    old.activity,
+                    old.date.format(FORMAT)
+                );
+                request_confirmation("Are you sure you want to delete this entry? [y/n]");
+            }
+            delete_entry(conn, old.id);
+            if let Some(s) = subject {
+                Session::trim(conn, &s, old.date);
+            }
+        }
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_entry_usage();
+            process::exit(1);
+        }
+    }
+}
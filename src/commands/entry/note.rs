@@ -0,0 +1,56 @@
+//! Notes and tags of entries
+use crate::models::{Entry, Subject};
+use crate::schema::entry::dsl::entry;
+use crate::schema::entry::{id, note, tags};
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::{update, ExpressionMethods, QueryDsl};
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Adds a note and some tags to the entry of a subject on a date. Since there is one entry per subject, day and
+/// activity type, the note is appended to the previous one (if any), and the tags are added to the previous ones.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject of the entry.
+/// * `when` - Date of the entry.
+/// * `activity_name` - Activity type of the entry.
+/// * `new_note` - Note to add.
+/// * `new_tags` - Tags to add (already normalized).
+pub fn annotate(
+    conn: &mut SqliteConnection,
+    subject: &Subject,
+    when: NaiveDate,
+    activity_name: &str,
+    new_note: Option<String>,
+    new_tags: &[String],
+) {
+    let old = match Entry::fetch_by_day(when, conn)
+        .into_iter()
+        .find(|e| e.subject_id == subject.id && e.activity == activity_name)
+    {
+        Some(e) => e,
+        // There is nothing to annotate if there is no time (e.g.: it was set to zero).
+        None => return,
+    };
+    let merged_note = match (&old.note, new_note) {
+        (Some(o), Some(n)) => Some(format!("{o}; {n}")),
+        (o, n) => n.or(o.clone()),
+    };
+    let mut merged_tags = old.tag_list();
+    for t in new_tags {
+        if !merged_tags.contains(t) {
+            merged_tags.push(t.clone());
+        }
+    }
+    let merged_tags = Some(merged_tags.join(",")).filter(|t| !t.is_empty());
+    match update(entry.filter(id.eq(old.id)))
+        .set((note.eq(merged_note), tags.eq(merged_tags)))
+        .execute(conn)
+    {
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Failed to save the note of the entry: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Set time command
 use crate::models::Subject;
 use crate::schema::entry::dsl::entry;
-use crate::schema::entry::{date, dedicated_time, subject_id};
+use crate::schema::entry::{activity, date, dedicated_time, subject_id};
 use diesel::dsl::delete;
 use diesel::internal::derives::multiconnection::chrono::NaiveDate;
 use diesel::QueryDsl;
@@ -14,13 +14,21 @@ use std::process;
 /// * `conn` - Database connection
 /// * `subject` - Subject studied.
 /// * `when` - Date when studied.
+/// * `activity_name` - Activity type of the time.
 /// * `amount` - Amount to set.
-pub fn set_time(conn: &mut SqliteConnection, subject: &Subject, when: NaiveDate, amount: i32) {
+pub fn set_time(
+    conn: &mut SqliteConnection,
+    subject: &Subject,
+    when: NaiveDate,
+    activity_name: &str,
+    amount: i32,
+) {
     if amount == 0 {
         match delete(
             entry
                 .filter(date.eq(when))
-                .filter(subject_id.eq(subject.id)),
+                .filter(subject_id.eq(subject.id))
+                .filter(activity.eq(activity_name)),
         )
         .execute(conn)
         {
@@ -34,7 +42,8 @@ pub fn set_time(conn: &mut SqliteConnection, subject: &Subject, when: NaiveDate,
         match update(
             entry
                 .filter(date.eq(when))
-                .filter(subject_id.eq(subject.id)),
+                .filter(subject_id.eq(subject.id))
+                .filter(activity.eq(activity_name)),
         )
         .set(dedicated_time.eq(amount))
         .execute(conn)
@@ -45,6 +54,7 @@ pub fn set_time(conn: &mut SqliteConnection, subject: &Subject, when: NaiveDate,
                         .values((
                             date.eq(when),
                             subject_id.eq(subject.id),
+                            activity.eq(activity_name),
                             dedicated_time.eq(amount),
                         ))
                         .execute(conn)
@@ -1,7 +1,7 @@
 //! Substract time command
 use crate::models::Subject;
 use crate::schema::entry::dsl::entry;
-use crate::schema::entry::{date, dedicated_time, subject_id};
+use crate::schema::entry::{activity, date, dedicated_time, subject_id};
 use diesel::dsl::delete;
 use diesel::internal::derives::multiconnection::chrono::NaiveDate;
 use diesel::SqliteConnection;
@@ -13,22 +13,25 @@ use std::process;
 /// * `conn` - Database connection
 /// * `subject` - Subject studied.
 /// * `when` - Date when studied.
+/// * `activity_name` - Activity type of the time.
 /// * `amount_to_substract` - Amount to substract to the current time.
 pub fn subtract_time(
     conn: &mut SqliteConnection,
     subject: &Subject,
     when: NaiveDate,
+    activity_name: &str,
     amount_to_substract: i32,
 ) {
     let amount = std::cmp::max(
-        subject.total_dedicated_time_day(when, conn) - amount_to_substract,
+        subject.activity_time_interval(conn, (when, when), activity_name) - amount_to_substract,
         0,
     );
     if amount == 0 {
         match delete(
             entry
                 .filter(subject_id.eq(subject.id))
-                .filter(date.eq(when)),
+                .filter(date.eq(when))
+                .filter(activity.eq(activity_name)),
         )
         .execute(conn)
         {
@@ -42,7 +45,8 @@ pub fn subtract_time(
         match update(
             entry
                 .filter(date.eq(when))
-                .filter(subject_id.eq(subject.id)),
+                .filter(subject_id.eq(subject.id))
+                .filter(activity.eq(activity_name)),
         )
         .set(dedicated_time.eq(amount))
         .execute(conn)
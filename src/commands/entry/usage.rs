@@ -3,9 +3,23 @@ use crate::FORMAT;
 /// Displays the bad usage message of the entry module.
 pub fn display_bad_usage() {
     println!(
-        "Bad usage: {} add/subtract/set [when] (subject id or short name) (amount):\n
+        "Bad usage: {} add/subtract/set [when] (subject id or short name) (amount) [--type (activity type)] [--focus (1-5)] [--at (HH:MM)] [--note (text)] [--tag (tag)...]:\n
+        The subject is searched in every plan ocurring on the date. If more than one has it, use its id.
+        --type is the activity type of the time (study by default), --focus rates how focused the added or set time was (from 1 to 5), --at records when the added time started (for `analyze hours`), and --note and --tag (which can be repeated) add a note and tags to the entry of the day.
         The date format is: {FORMAT}\n\
     ",
         crate::env::args().collect::<Vec<String>>().first().unwrap()
     );
 }
+
+/// Displays the bad usage message of the entry command.
+pub fn display_entry_usage() {
+    println!(
+        "Bad usage: {} entry ...:\n
+        - edit (entry id) [--minutes (time)] [--date (date)] [--subject (id/short name)] [--type (activity type)] [--focus (1-5/none)]: Changes the time, date, subject, activity type or focus of an entry.
+        - delete (entry id) [--confirm]: Deletes an entry.
+        Use `log` to see the ids of the entries. The date format is: {FORMAT}\n\
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
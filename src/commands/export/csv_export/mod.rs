@@ -1,13 +1,20 @@
 //! # CSV Export Module
-//! CSV exports are generated with the header `["date", subject1.short_name, subject2.short_name, ...]`.
-//! The corresponding values below are dates and the respective study time for each date.
+//! CSV exports are generated with the header `["date", "phase", subject1.short_name, subject2.short_name, ...,
+//! "category:name1", ..., "type:name1", ...]`.
+//! The corresponding values below are dates, the phases of the period they are in (separated by slashes) and the
+//! respective study time for each date, of every subject, of every category and of every activity type. The values of archived subjects are
+//! left empty from their archive date on.
 //! These 'dates' may also represent intervals, depending on the selected export mode.
+//!
+//! The entries export mode uses a long format instead: one line per entry, with its date, phase, subject, minutes,
+//! activity type, focus, effective minutes, tags and note.
 mod write_daily;
+mod write_entries;
 mod write_monthly;
 mod write_weekly;
 
 use super::ExportMode;
-use crate::models::{Period, Subject};
+use crate::models::{ActivityType, Period, Phase, Subject};
 use csv::Writer;
 use diesel::internal::derives::multiconnection::chrono::{Local, NaiveDate};
 use diesel::SqliteConnection;
@@ -15,6 +22,7 @@ use std::fs::File;
 use std::path::PathBuf;
 use std::{fs, process};
 use write_daily::write_daily;
+use write_entries::write_entries;
 use write_monthly::write_monthly;
 use write_weekly::write_weekly;
 
@@ -23,14 +31,120 @@ const DATETIME_FILENAME_EXPORT_FORMAT: &str = "%Y%m%d_%H%M%S";
 /// Date format when exporting monthly data.
 const MONTHLY_FORMAT: &str = "%m-%Y";
 
-/// Gets a csv header based on the short name of the provided list of subjects.
+/// Column of an export: the time of a subject, or of every subject of a course (so a year-long subject is exported
+/// as one column).
+pub(crate) struct ExportColumn {
+    /// Header of the column.
+    name: String,
+    /// Subjects whose time is summed in the column.
+    subjects: Vec<Subject>,
+    /// Activity type whose time is summed, or None to sum the time of every type.
+    activity: Option<String>,
+}
+
+impl ExportColumn {
+    /// Gets the time dedicated in an interval to the subjects of the column.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `interval` - Date interval (start, end).
+    fn time_interval(&self, conn: &mut SqliteConnection, interval: (NaiveDate, NaiveDate)) -> i32 {
+        self.subjects
+            .iter()
+            .map(|s| match &self.activity {
+                Some(a) => s.activity_time_interval(conn, interval, a),
+                None => s.total_dedicated_time_interval(conn, interval),
+            })
+            .sum()
+    }
+
+    /// Gets the value of the column in an interval: its time, or nothing if every subject of the column was already
+    /// archived when the interval started.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `interval` - Date interval (start, end).
+    fn value(&self, conn: &mut SqliteConnection, interval: (NaiveDate, NaiveDate)) -> String {
+        if self.subjects.iter().all(|s| s.is_archived(interval.0)) {
+            return String::new();
+        }
+        self.time_interval(conn, interval).to_string()
+    }
+}
+
+/// Gets the columns of the export of some periods. With one period, every subject is a column. With more, every
+/// course is a column, named after the short name of its first subject (and its id, if another course has the same
+/// short name). They are followed by a `category:name` column for every category, and a `type:name` column for every
+/// activity type.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `periods` - Periods to export, sorted by start date.
+pub(crate) fn get_columns(conn: &mut SqliteConnection, periods: &[Period]) -> Vec<ExportColumn> {
+    let mut columns: Vec<(i32, ExportColumn)> = Vec::new();
+    let mut categories: Vec<ExportColumn> = Vec::new();
+    for p in periods {
+        let period_subjects = p.fetch_subjects(conn);
+        for s in &period_subjects {
+            if let Some(category) = s.effective_category(&period_subjects) {
+                let name = format!("category:{category}");
+                match categories.iter_mut().find(|c| c.name == name) {
+                    Some(column) => column.subjects.push(s.clone()),
+                    None => categories.push(ExportColumn {
+                        name,
+                        subjects: vec![s.clone()],
+                        activity: None,
+                    }),
+                }
+            }
+        }
+        for s in period_subjects {
+            let course = match periods.len() {
+                1 => s.id,
+                _ => s.course_id.unwrap_or(s.id),
+            };
+            match columns.iter_mut().find(|(c, _)| *c == course) {
+                Some((_, column)) => column.subjects.push(s),
+                None => columns.push((
+                    course,
+                    ExportColumn {
+                        name: s.short_name.clone(),
+                        subjects: vec![s],
+                        activity: None,
+                    },
+                )),
+            }
+        }
+    }
+    let all_subjects: Vec<Subject> = columns
+        .iter()
+        .flat_map(|(_, c)| c.subjects.clone())
+        .collect();
+    let types = ActivityType::fetch_all(conn)
+        .into_iter()
+        .map(|t| ExportColumn {
+            name: format!("type:{t}"),
+            subjects: all_subjects.clone(),
+            activity: Some(t.name),
+        });
+    let names: Vec<String> = columns.iter().map(|(_, c)| c.name.clone()).collect();
+    columns
+        .into_iter()
+        .map(|(course, mut column)| {
+            if names.iter().filter(|n| **n == column.name).count() > 1 {
+                column.name = format!("{} (ID:{course})", column.name);
+            }
+            column
+        })
+        .chain(categories)
+        .chain(types)
+        .collect()
+}
+
+/// Gets a csv header based on the names of the provided list of columns.
 /// # Arguments
-/// * `subjects` - List of subjects.
-fn get_header(subjects: &Vec<Subject>) -> Vec<String> {
-    let mut header = vec![String::from("date")];
-    for i in subjects {
-        let column = i.short_name.clone();
-        header.push(column);
+/// * `columns` - List of columns.
+fn get_header(columns: &[ExportColumn]) -> Vec<String> {
+    let mut header = vec![String::from("date"), String::from("phase")];
+    for i in columns {
+        header.push(i.name.clone());
     }
     header
 }
@@ -83,32 +197,39 @@ fn get_file_path(path: &str, mode: &ExportMode, descr: String) -> PathBuf {
     }
 }
 
-/// It exports all the data from a period in a specific interval and export mode to a file in the given path.
+/// It exports all the data from some periods in a specific interval and export mode to a file in the given path.
 /// # Arguments
 /// * `conn` - Database connection
-/// * `period` - Period from which export the data.
+/// * `description` - Description of the export, used in the folder name.
+/// * `columns` - Columns to export.
+/// * `phases` - Phases of the exported periods.
 /// * `date_interval` - First day and last day of data.
 /// * `program_path` - Folder where will be created the data file.
 /// * `mode` - Export mode.
 pub fn csv_export(
     conn: &mut SqliteConnection,
-    period: &Period,
+    description: &str,
+    columns: &[ExportColumn],
+    phases: &[Phase],
     date_interval: (&NaiveDate, &NaiveDate),
     program_path: &str,
     mode: ExportMode,
 ) {
-    let mut descr = period.description.clone();
+    let mut descr = description.to_string();
     descr.truncate(10);
     let path = get_file_path(program_path, &mode, descr);
     match mode {
         ExportMode::Daily => {
-            write_daily(conn, &path, period, date_interval);
+            write_daily(conn, &path, columns, phases, date_interval);
         }
         ExportMode::Weekly => {
-            write_weekly(conn, &path, period, date_interval);
+            write_weekly(conn, &path, columns, phases, date_interval);
         }
         ExportMode::Monthly => {
-            write_monthly(conn, &path, period, date_interval);
+            write_monthly(conn, &path, columns, phases, date_interval);
+        }
+        ExportMode::Entries => {
+            write_entries(conn, &path, columns, phases, date_interval);
         }
     }
     println!(
@@ -1,6 +1,6 @@
 //! Handles daily csv export format
-use crate::commands::export::csv_export::{get_csv_writer, get_header};
-use crate::models::Period;
+use crate::commands::export::csv_export::{get_csv_writer, get_header, ExportColumn};
+use crate::models::Phase;
 use crate::FORMAT;
 use diesel::internal::derives::multiconnection::chrono::{NaiveDate, TimeDelta};
 use diesel::SqliteConnection;
@@ -8,21 +8,22 @@ use std::ops::Add;
 use std::path::PathBuf;
 use std::process;
 
-/// Writes the period study time data by days.
+/// Writes the study time data of some columns by days.
 /// # Arguments
 /// * `conn` - Database connection.
 /// * `file` - File path to write.
-/// * `period` - Study period.
+/// * `columns` - Columns to export.
+/// * `period_phases` - Phases of the exported periods.
 /// * `date_interval` - Date interval to search entries.
 pub(crate) fn write_daily(
     conn: &mut SqliteConnection,
     file: &PathBuf,
-    period: &Period,
+    columns: &[ExportColumn],
+    period_phases: &[Phase],
     date_interval: (&NaiveDate, &NaiveDate),
 ) {
-    let subjects = period.fetch_subjects(conn);
     let mut writer = get_csv_writer(file);
-    match writer.write_record(get_header(&subjects)) {
+    match writer.write_record(get_header(columns)) {
         Ok(_) => {}
         Err(e) => {
             eprintln!("Failed to write when writing header: {e}");
@@ -33,8 +34,9 @@ pub(crate) fn write_daily(
     while i.le(date_interval.1) {
         let mut record: Vec<String> = Vec::new();
         record.push(i.format(FORMAT).to_string());
-        for j in &subjects {
-            record.push(j.total_dedicated_time_day(i, conn).to_string());
+        record.push(Phase::names_overlapping(period_phases, (i, i)));
+        for j in columns {
+            record.push(j.value(conn, (i, i)));
         }
         match writer.write_record(record) {
             Ok(_) => {}
@@ -0,0 +1,77 @@
+//! Handles the long (one line per entry) csv export format
+use super::{get_csv_writer, ExportColumn};
+use crate::models::{Entry, Phase, Subject};
+use crate::FORMAT;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::SqliteConnection;
+use std::path::PathBuf;
+use std::process;
+
+/// Header of the long export format.
+const HEADER: [&str; 10] = [
+    "date",
+    "phase",
+    "subject",
+    "name",
+    "minutes",
+    "type",
+    "focus",
+    "effective_minutes",
+    "tags",
+    "note",
+];
+
+/// Writes every entry of the subjects of some columns as a line, with its activity type, focus, effective minutes, tags (separated by spaces) and note.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `file` - File path to write.
+/// * `columns` - Columns whose subjects are exported.
+/// * `period_phases` - Phases of the exported periods.
+/// * `date_interval` - Date interval to search entries.
+pub(crate) fn write_entries(
+    conn: &mut SqliteConnection,
+    file: &PathBuf,
+    columns: &[ExportColumn],
+    period_phases: &[Phase],
+    date_interval: (&NaiveDate, &NaiveDate),
+) {
+    let mut writer = get_csv_writer(file);
+    match writer.write_record(HEADER) {
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Failed to write when writing header: {e}");
+            process::exit(1);
+        }
+    }
+    // Subjects are in a subject (or course) column and in a category column, so they are deduplicated.
+    let mut subject_list: Vec<&Subject> = Vec::new();
+    for s in columns.iter().flat_map(|c| &c.subjects) {
+        if !subject_list.iter().any(|o| o.id == s.id) {
+            subject_list.push(s);
+        }
+    }
+    let ids: Vec<i32> = subject_list.iter().map(|s| s.id).collect();
+    let interval = (Some(*date_interval.0), Some(*date_interval.1));
+    for e in Entry::fetch_by_subjects(conn, &ids, interval) {
+        let subject = subject_list.iter().find(|s| s.id == e.subject_id).unwrap();
+        let record = vec![
+            e.date.format(FORMAT).to_string(),
+            Phase::names_overlapping(period_phases, (e.date, e.date)),
+            subject.short_name.clone(),
+            subject.name.clone(),
+            e.dedicated_time.to_string(),
+            e.activity.clone(),
+            e.focus.map(|f| format!("{f:.1}")).unwrap_or_default(),
+            format!("{:.0}", e.effective_minutes()),
+            e.tag_list().join(" "),
+            e.note.clone().unwrap_or_default(),
+        ];
+        match writer.write_record(record) {
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Failed to export data: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -1,25 +1,28 @@
 //! Handles monthly csv export format
-use crate::commands::export::csv_export::{get_csv_writer, get_header, MONTHLY_FORMAT};
-use crate::models::Period;
-use diesel::internal::derives::multiconnection::chrono::{Datelike, NaiveDate};
+use crate::commands::export::csv_export::{
+    get_csv_writer, get_header, ExportColumn, MONTHLY_FORMAT,
+};
+use crate::models::Phase;
+use diesel::internal::derives::multiconnection::chrono::{Datelike, Months, NaiveDate};
 use diesel::SqliteConnection;
 use std::path::PathBuf;
 use std::process;
-/// Writes the period study time data by months.
+/// Writes the study time data of some columns by months.
 /// # Arguments
 /// * `conn` - Database connection.
 /// * `file` - File path to write.
-/// * `period` - Study period.
+/// * `columns` - Columns to export.
+/// * `period_phases` - Phases of the exported periods.
 /// * `date_interval` - Date interval to search entries.
 pub(crate) fn write_monthly(
     conn: &mut SqliteConnection,
     file: &PathBuf,
-    period: &Period,
+    columns: &[ExportColumn],
+    period_phases: &[Phase],
     date_interval: (&NaiveDate, &NaiveDate),
 ) {
-    let subjects = period.fetch_subjects(conn);
     let mut writer = get_csv_writer(file);
-    match writer.write_record(get_header(&subjects)) {
+    match writer.write_record(get_header(columns)) {
         Ok(_) => {}
         Err(e) => {
             eprintln!("Failed to write when writing header: {e}");
@@ -28,20 +31,15 @@ pub(crate) fn write_monthly(
     }
     let mut i = *date_interval.0;
     while i.le(date_interval.1) {
-        let interval_to_fetch = (
-            NaiveDate::from_ymd_opt(i.year(), i.month(), 1).unwrap(),
-            NaiveDate::from_ymd_opt(i.year(), i.month() + 1, 1)
-                .unwrap()
-                .pred_opt()
-                .unwrap(),
-        );
+        let first_day = NaiveDate::from_ymd_opt(i.year(), i.month(), 1).unwrap();
+        // Exports spanning several periods cross the end of the year, so months are added with `Months`.
+        let next_month = first_day + Months::new(1);
+        let interval_to_fetch = (first_day, next_month.pred_opt().unwrap());
         let mut record: Vec<String> = Vec::new();
         record.push(format!("{}", i.format(MONTHLY_FORMAT)));
-        for j in &subjects {
-            record.push(
-                j.total_dedicated_time_interval(conn, interval_to_fetch)
-                    .to_string(),
-            );
+        record.push(Phase::names_overlapping(period_phases, interval_to_fetch));
+        for j in columns {
+            record.push(j.value(conn, interval_to_fetch));
         }
         match writer.write_record(record) {
             Ok(_) => {}
@@ -50,8 +48,6 @@ pub(crate) fn write_monthly(
                 process::exit(1);
             }
         }
-        i = i
-            .with_month(std::cmp::max((i.month() + 1) % 13, 1))
-            .unwrap();
+        i = next_month;
     }
 }
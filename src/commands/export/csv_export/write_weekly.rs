@@ -1,7 +1,7 @@
 //! Handles weekly csv export format
-use super::{get_csv_writer, get_header};
+use super::{get_csv_writer, get_header, ExportColumn};
 use crate::commands::status::WEEKDAY_START;
-use crate::models::Period;
+use crate::models::Phase;
 use crate::FORMAT;
 use diesel::internal::derives::multiconnection::chrono::{NaiveDate, TimeDelta};
 use diesel::SqliteConnection;
@@ -9,21 +9,22 @@ use std::ops::Add;
 use std::path::PathBuf;
 use std::process;
 
-/// Writes the period study time data by weeks.
+/// Writes the study time data of some columns by weeks.
 /// # Arguments
 /// * `conn` - Database connection.
 /// * `file` - File path to write.
-/// * `period` - Study period.
+/// * `columns` - Columns to export.
+/// * `period_phases` - Phases of the exported periods.
 /// * `date_interval` - Date interval to search entries.
 pub(crate) fn write_weekly(
     conn: &mut SqliteConnection,
     file: &PathBuf,
-    period: &Period,
+    columns: &[ExportColumn],
+    period_phases: &[Phase],
     date_interval: (&NaiveDate, &NaiveDate),
 ) {
-    let subjects = period.fetch_subjects(conn);
     let mut writer = get_csv_writer(file);
-    match writer.write_record(get_header(&subjects)) {
+    match writer.write_record(get_header(columns)) {
         Ok(_) => {}
         Err(e) => {
             eprintln!("Failed to write when writing header: {e}");
@@ -42,11 +43,9 @@ pub(crate) fn write_weekly(
             interval_to_fetch.0.format(FORMAT),
             interval_to_fetch.1.format(FORMAT)
         ));
-        for j in &subjects {
-            record.push(
-                j.total_dedicated_time_interval(conn, interval_to_fetch)
-                    .to_string(),
-            );
+        record.push(Phase::names_overlapping(period_phases, interval_to_fetch));
+        for j in columns {
+            record.push(j.value(conn, interval_to_fetch));
         }
         match writer.write_record(record) {
             Ok(_) => {}
@@ -4,8 +4,8 @@
 use crate::commands::export::usage::display_bad_usage;
 use crate::commands::plan::{get_date_arg, get_plan_arg};
 use crate::get_data_dir;
-use crate::models::Period;
-use csv_export::csv_export;
+use crate::models::{Period, Phase};
+use csv_export::{csv_export, get_columns};
 use diesel::SqliteConnection;
 use std::fmt::{Display, Formatter};
 use std::process;
@@ -21,6 +21,8 @@ enum ExportMode {
     Weekly,
     /// Export study time month by month.
     Monthly,
+    /// Export every entry, with its tags and note (long format).
+    Entries,
 }
 
 impl Display for ExportMode {
@@ -29,6 +31,7 @@ impl Display for ExportMode {
             ExportMode::Daily => f.write_str("daily"),
             ExportMode::Weekly => f.write_str("weekly"),
             ExportMode::Monthly => f.write_str("monthly"),
+            ExportMode::Entries => f.write_str("entries"),
         }
     }
 }
@@ -38,80 +41,78 @@ impl Display for ExportMode {
 /// * `args`: Remaining program arguments.
 /// * `conn` : Database connection.
 pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
-    let plan_id = get_plan_arg(args, conn);
+    // With --all, every plan is exported at once, and linked subjects (courses) are one column.
+    let all = match args.iter().position(|a| a == "--all") {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    };
+    let mut periods = match all {
+        true => Period::fetch_all_plans(conn),
+        false => {
+            let plan_id = get_plan_arg(args, conn);
+            match Period::from_id(conn, plan_id) {
+                Some(period) => vec![period],
+                None => {
+                    eprintln!("There is no period with the provided id");
+                    process::exit(1);
+                }
+            }
+        }
+    };
     if args.is_empty() || args.first().unwrap().starts_with("--") {
         display_bad_usage();
         process::exit(1);
     }
-    let period = match Period::from_id(conn, plan_id) {
-        Some(period) => period,
-        None => {
-            eprintln!("There is no period with the provided id");
-            process::exit(1);
-        }
-    };
+    if periods.is_empty() {
+        println!("No periods created yet.");
+        return;
+    }
+    periods.sort_by_key(|p| p.initial_date);
     let start_date = match args.contains(&"--start".to_string()) {
         true => get_date_arg(args, "--start"),
-        false => period.initial_date,
+        false => periods.first().unwrap().initial_date,
     };
     let end_date = match args.contains(&"--end".to_string()) {
         true => get_date_arg(args, "--end"),
-        false => period.final_date,
+        false => periods.iter().map(|p| p.end_or_today()).max().unwrap(),
+    };
+    let description = match all {
+        true => String::from("All plans"),
+        false => periods.first().unwrap().description.clone(),
     };
-    let mut header = vec![String::from("date")];
-    let subjects = period.fetch_subjects(conn);
-    for i in &subjects {
-        let column = i.short_name.clone();
-        header.push(column);
+    let columns = get_columns(conn, &periods);
+    let mut phases: Vec<Phase> = Vec::new();
+    for p in &periods {
+        phases.extend(Phase::fetch_by_period(conn, p.id));
     }
-    match args.first().unwrap().trim() {
-        "daily" => csv_export(
-            conn,
-            &period,
-            (&start_date, &end_date),
-            &get_data_dir(),
+    let modes = match args.first().unwrap().trim() {
+        "daily" => vec![ExportMode::Daily],
+        "weekly" => vec![ExportMode::Weekly],
+        "monthly" => vec![ExportMode::Monthly],
+        "entries" => vec![ExportMode::Entries],
+        "all" => vec![
             ExportMode::Daily,
-        ),
-        "weekly" => csv_export(
-            conn,
-            &period,
-            (&start_date, &end_date),
-            &get_data_dir(),
             ExportMode::Weekly,
-        ),
-        "monthly" => csv_export(
-            conn,
-            &period,
-            (&start_date, &end_date),
-            &get_data_dir(),
             ExportMode::Monthly,
-        ),
-        "all" => {
-            csv_export(
-                conn,
-                &period,
-                (&start_date, &end_date),
-                &get_data_dir(),
-                ExportMode::Daily,
-            );
-            csv_export(
-                conn,
-                &period,
-                (&start_date, &end_date),
-                &get_data_dir(),
-                ExportMode::Weekly,
-            );
-            csv_export(
-                conn,
-                &period,
-                (&start_date, &end_date),
-                &get_data_dir(),
-                ExportMode::Monthly,
-            );
-        }
+            ExportMode::Entries,
+        ],
         _ => {
             display_bad_usage();
             process::exit(1);
         }
+    };
+    for mode in modes {
+        csv_export(
+            conn,
+            &description,
+            &columns,
+            &phases,
+            (&start_date, &end_date),
+            &get_data_dir(),
+            mode,
+        );
     }
 }
@@ -4,12 +4,14 @@ use crate::FORMAT;
 /// Displays the bad usage message from export module.
 pub fn display_bad_usage() {
     println!(
-        "Bad usage: {} export (mode) [--plan (plan id)] [--start (start date)] [--end (end date)]:\n\
+        "Bad usage: {} export (mode) [--plan (plan id) / --all] [--start (start date)] [--end (end date)]:\n\
+        With --all, every plan is exported at once, and the subjects of a course are one column.\n\
         Avaliable modes:\n\n
             \t- daily: One line is one day\n\
             \t- weekly: One line is one week\n\
             \t- monthly: One line is one month\n\
-            \t- all: Three files for every previous mode\n\
+            \t- entries: One line is one entry, with its tags and note\n\
+            \t- all: One file for every previous mode\n\
         The date format is: {FORMAT}\n\
     ",
         crate::env::args().collect::<Vec<String>>().first().unwrap()
@@ -0,0 +1,38 @@
+//! Handles goal listing.
+use crate::commands::goal::goal_name;
+use crate::models::{Goal, Period, Phase};
+use diesel::SqliteConnection;
+
+/// Lists all goals from a given period, grouped by subject.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the goals.
+pub fn list(conn: &mut SqliteConnection, period: &Period) {
+    println!(
+        "Goals from period {} (ID:{})",
+        period.description, period.id
+    );
+    let period_goals = Goal::fetch_by_period(conn, period.id);
+    if period_goals.is_empty() {
+        println!("No goals from this period");
+        return;
+    }
+    let period_phases = Phase::fetch_by_period(conn, period.id);
+    let mut owners = vec![None];
+    owners.extend(period.fetch_subjects(conn).into_iter().map(Some));
+    for owner in owners {
+        let owner_goals = period_goals
+            .iter()
+            .filter(|g| g.subject_id == owner.as_ref().map(|s| s.id))
+            .collect::<Vec<&Goal>>();
+        if !owner_goals.is_empty() {
+            println!("{}:", goal_name(owner.as_ref()));
+            for g in owner_goals {
+                match period_phases.iter().find(|p| Some(p.id) == g.phase_id) {
+                    Some(p) => println!("\t{g} - {} phase", p.name),
+                    None => println!("\t{g}"),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! # Study time goals.
+//! Goals are daily and/or weekly study time targets of a subject, or of the whole period (the sum of every subject)
+//! when no subject is given. Every goal is effective from a date on, until another goal of the same subject replaces
+//! it. That way, past days and weeks are judged against the targets that applied back then.
+
+mod list;
+mod remove;
+mod report;
+mod set;
+mod usage;
+
+use crate::commands::goal::usage::display_bad_usage;
+use crate::commands::plan::{get_date_arg, get_plan_arg, get_plan_phase};
+use crate::commands::subject::get_plan_subject;
+use crate::interpreter::{detect_unknown_arg, get_specific_arg, parse_duration};
+use crate::models::{Period, Subject};
+use crate::{debug_println, today};
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Gets the time dedicated in an interval to the subject of a goal, or to the whole period if there is no subject.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the goal.
+/// * `subject` - Subject of the goal, if any.
+/// * `interval` - Date interval (start, end).
+pub(crate) fn goal_time_interval(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    subject: Option<&Subject>,
+    interval: (NaiveDate, NaiveDate),
+) -> i32 {
+    match subject {
+        Some(s) => s.total_dedicated_time_interval(conn, interval),
+        None => period.total_dedicated_time_interval(conn, interval),
+    }
+}
+
+/// Gets the name to display for the subject of a goal.
+/// # Arguments
+/// * `subject` - Subject of the goal, where None means the whole period.
+pub(crate) fn goal_name(subject: Option<&Subject>) -> String {
+    match subject {
+        Some(s) => s.name.clone(),
+        None => String::from("All subjects"),
+    }
+}
+
+/// Gets the value of a goal time option. Returns None if the option isn't given, and Some(None) if the goal
+/// has to be cleared (`none`).
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `find` - Option to find.
+fn get_goal_time_arg(args: &mut [String], find: &str) -> Option<Option<i32>> {
+    if !args.contains(&find.to_string()) {
+        return None;
+    }
+    match get_specific_arg(args, find) {
+        Some(t) if t.trim().to_lowercase() == "none" => Some(None),
+        Some(t) => match parse_duration(&t) {
+            Some(minutes) => Some(Some(minutes)),
+            None => {
+                eprintln!("Failed to parse the time of {find}. Use minutes (90), hours (8h) or both (1h30m).");
+                process::exit(1);
+            }
+        },
+        None => {
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
+
+/// Interprets goal subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    let plan_id = get_plan_arg(args, conn);
+    let period = match Period::from_id(conn, plan_id) {
+        Some(period) => period,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    };
+    match option.trim() {
+        "set" => {
+            if args.is_empty() || args.first().unwrap().starts_with("--") {
+                display_bad_usage();
+                process::exit(1);
+            }
+            if let Some(o) = detect_unknown_arg(
+                args,
+                &vec!["--daily", "--weekly", "--from", "--phase"],
+                "--",
+            ) {
+                eprintln!("Unknown argument: {o}");
+                display_bad_usage();
+                process::exit(1);
+            }
+            let subject = match args.first().unwrap().trim() {
+                "all" => None,
+                s => Some(get_plan_subject(&s.to_string(), conn, plan_id)),
+            };
+            let phase = match args.contains(&"--phase".to_string()) {
+                true => match get_specific_arg(args, "--phase") {
+                    Some(p) => Some(get_plan_phase(&p, conn, plan_id)),
+                    None => {
+                        display_bad_usage();
+                        process::exit(1);
+                    }
+                },
+                false => None,
+            };
+            let from = match (args.contains(&"--from".to_string()), &phase) {
+                (true, Some(_)) => {
+                    eprintln!("A phase goal is effective from the start of the phase, so --from can't be given.");
+                    process::exit(1);
+                }
+                (true, None) => get_date_arg(args, "--from"),
+                (false, Some(p)) => p.start_date,
+                (false, None) => today().max(period.initial_date),
+            };
+            if from < period.initial_date || from > period.end_date() {
+                eprintln!("The goal must start inside the period.");
+                process::exit(1);
+            }
+            let daily = get_goal_time_arg(args, "--daily");
+            let weekly = get_goal_time_arg(args, "--weekly");
+            if daily.is_none() && weekly.is_none() {
+                display_bad_usage();
+                process::exit(1);
+            }
+            set::set_goal(
+                conn,
+                &period,
+                subject.as_ref(),
+                from,
+                phase.as_ref(),
+                daily,
+                weekly,
+            );
+        }
+        "list" => {
+            list::list(conn, &period);
+        }
+        "remove" => match args.first().map(|i| i.parse::<i32>()) {
+            Some(Ok(goal_id)) => remove::remove(conn, goal_id),
+            _ => {
+                display_bad_usage();
+                process::exit(1);
+            }
+        },
+        "report" => {
+            report::report(conn, &period);
+        }
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
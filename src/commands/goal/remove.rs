@@ -0,0 +1,27 @@
+//! Handles goal removal.
+use crate::schema::goals::dsl::goals;
+use crate::schema::goals::id;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Removes an existing goal.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `goal_id` - Goal's id.
+pub fn remove(conn: &mut SqliteConnection, goal_id: i32) {
+    match delete(goals.filter(id.eq(goal_id))).execute(conn) {
+        Ok(0) => {
+            eprintln!("There is no goal with id {goal_id}");
+            process::exit(1);
+        }
+        Ok(_) => {
+            println!("Goal removed succesfully");
+        }
+        Err(e) => {
+            eprintln!("Error deleting goal: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Handles the goal report, with the goals reached every week.
+use crate::commands::goal::{goal_name, goal_time_interval};
+use crate::commands::status::WEEKDAY_START;
+use crate::models::{Break, Goal, Period, Phase, Subject};
+use crate::{format_hours_and_minutes, today, FORMAT};
+use colored::Colorize;
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, TimeDelta};
+use diesel::SqliteConnection;
+
+/// Number of goals reached and number of goals to reach.
+#[derive(Default)]
+struct HitRate {
+    weekly: (i32, i32),
+    daily: (i32, i32),
+}
+
+/// Formats a hit rate as `hits/total (percentage)`.
+/// # Arguments
+/// * `rate` - (hits, total) tuple.
+fn format_rate(rate: (i32, i32)) -> String {
+    match rate.1 {
+        0 => String::from("-"),
+        total => format!(
+            "{}/{} ({:.1}%)",
+            rate.0,
+            total,
+            rate.0 as f64 * 100.0 / total as f64
+        ),
+    }
+}
+
+/// Prints, week by week, which goals of a period were reached, and the hit rate of every subject at the end.
+/// Every week is judged against the weekly goal in effect on its last day, and every day against the daily goal
+/// in effect on that day. Weeks fully inside a break and days of a break aren't judged, and weeks are grouped by
+/// the phase they start in.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the goals.
+pub fn report(conn: &mut SqliteConnection, period: &Period) {
+    println!("Goal report of {} (ID:{})", period.description, period.id);
+    let period_goals = Goal::fetch_by_period(conn, period.id);
+    let mut owners: Vec<Option<Subject>> = vec![None];
+    owners.extend(period.fetch_subjects(conn).into_iter().map(Some));
+    owners.retain(|o| {
+        period_goals
+            .iter()
+            .any(|g| g.subject_id == o.as_ref().map(|s| s.id))
+    });
+    if owners.is_empty() {
+        println!("No goals from this period");
+        return;
+    }
+    let last_day = today().min(period.end_date());
+    let breaks = Break::fetch_by_period(conn, period.id);
+    let period_phases = Phase::fetch_by_period(conn, period.id);
+    let mut current_phase: Option<i32> = None;
+    let mut rates: Vec<HitRate> = owners.iter().map(|_| HitRate::default()).collect();
+    let mut week = period.initial_date.week(WEEKDAY_START).first_day();
+    while week <= last_day {
+        let interval = (
+            week.max(period.initial_date),
+            (week + TimeDelta::days(6)).min(last_day),
+        );
+        // Weeks are grouped by the phase they start in.
+        let phase = Phase::on(&period_phases, interval.0);
+        if phase.map(|p| p.id) != current_phase {
+            match phase {
+                Some(p) => println!("== {} phase ==", p.name),
+                None => println!("== No phase =="),
+            }
+            current_phase = phase.map(|p| p.id);
+        }
+        if let Some(label) = Break::covering_label(&breaks, interval) {
+            println!(
+                "{}:{} break ({label}), not judged",
+                interval.0.format(FORMAT),
+                interval.1.format(FORMAT)
+            );
+            week += TimeDelta::weeks(1);
+            continue;
+        }
+        println!(
+            "{}:{}",
+            interval.0.format(FORMAT),
+            interval.1.format(FORMAT)
+        );
+        for (owner, rate) in owners.iter().zip(rates.iter_mut()) {
+            let owner_id = owner.as_ref().map(|s| s.id);
+            let mut parts: Vec<String> = Vec::new();
+            let weekly_goal =
+                Goal::effective(conn, period.id, owner_id, interval.1).and_then(|g| g.weekly_time);
+            if let Some(goal) = weekly_goal {
+                let time = goal_time_interval(conn, period, owner.as_ref(), interval);
+                // The current week isn't judged until it ends, unless the goal is already reached.
+                let result = if time >= goal {
+                    rate.weekly.0 += 1;
+                    rate.weekly.1 += 1;
+                    "reached".green()
+                } else if week + TimeDelta::days(6) > last_day && last_day < period.end_date() {
+                    "in progress".yellow()
+                } else {
+                    rate.weekly.1 += 1;
+                    "missed".red()
+                };
+                parts.push(format!(
+                    "weekly {} / {} {}",
+                    format_hours_and_minutes(time),
+                    format_hours_and_minutes(goal),
+                    result
+                ));
+            }
+            let mut days = (0, 0);
+            // Today can still reach its daily goal, so only the finished days are counted.
+            let last_counted = match interval.1 == today() {
+                true => interval.1 - TimeDelta::days(1),
+                false => interval.1,
+            };
+            let mut day: NaiveDate = interval.0;
+            while day <= last_counted {
+                if breaks.iter().any(|b| b.contains(day)) {
+                    day += TimeDelta::days(1);
+                    continue;
+                }
+                if let Some(goal) =
+                    Goal::effective(conn, period.id, owner_id, day).and_then(|g| g.daily_time)
+                {
+                    days.1 += 1;
+                    if goal_time_interval(conn, period, owner.as_ref(), (day, day)) >= goal {
+                        days.0 += 1;
+                    }
+                }
+                day += TimeDelta::days(1);
+            }
+            if days.1 > 0 {
+                parts.push(format!("daily goal reached {}/{} days", days.0, days.1));
+                rate.daily.0 += days.0;
+                rate.daily.1 += days.1;
+            }
+            if !parts.is_empty() {
+                println!("\t * {}: {}", goal_name(owner.as_ref()), parts.join("; "));
+            }
+        }
+        week += TimeDelta::weeks(1);
+    }
+    println!("Hit rate:");
+    for (owner, rate) in owners.iter().zip(rates) {
+        println!(
+            "\t * {}: weekly {}, daily {}",
+            goal_name(owner.as_ref()),
+            format_rate(rate.weekly),
+            format_rate(rate.daily)
+        );
+    }
+}
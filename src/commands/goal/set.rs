@@ -0,0 +1,81 @@
+//! Handles goal setting.
+use crate::commands::goal::goal_name;
+use crate::models::{Goal, Period, Phase, Subject};
+use crate::schema::goals::dsl::goals;
+use crate::schema::goals::{
+    daily_time, effective_from, period_id, phase_id, subject_id, weekly_time,
+};
+use crate::FORMAT;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::SqliteConnection;
+use diesel::{delete, insert_into, Connection, ExpressionMethods, RunQueryDsl};
+use std::process;
+
+/// Sets the goals of a subject (or the whole period) from a date on. If a goal is not given, the one in effect on
+/// that date is kept. A previous goal starting on the same date (and of the same phase) is replaced.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the goal.
+/// * `subject` - Subject of the goal, where None means the whole period.
+/// * `from` - Date from which the goal is effective.
+/// * `phase` - Phase the goal is restricted to, if any.
+/// * `daily` - New daily goal, if given (None inside means no goal).
+/// * `weekly` - New weekly goal, if given (None inside means no goal).
+pub fn set_goal(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    subject: Option<&Subject>,
+    from: NaiveDate,
+    phase: Option<&Phase>,
+    daily: Option<Option<i32>>,
+    weekly: Option<Option<i32>>,
+) {
+    let owner = subject.map(|s| s.id);
+    let owner_phase = phase.map(|p| p.id);
+    let previous = Goal::general(conn, period.id, owner, from);
+    let new_daily = daily.unwrap_or(previous.as_ref().and_then(|g| g.daily_time));
+    let new_weekly = weekly.unwrap_or(previous.as_ref().and_then(|g| g.weekly_time));
+    let result = conn.transaction(|conn| {
+        let mut replaced = delete(goals)
+            .filter(period_id.eq(period.id))
+            .filter(effective_from.eq(from))
+            .into_boxed();
+        replaced = match owner {
+            Some(s) => replaced.filter(subject_id.eq(s)),
+            None => replaced.filter(subject_id.is_null()),
+        };
+        replaced = match owner_phase {
+            Some(p) => replaced.filter(phase_id.eq(p)),
+            None => replaced.filter(phase_id.is_null()),
+        };
+        replaced.execute(conn)?;
+        insert_into(goals)
+            .values((
+                period_id.eq(period.id),
+                subject_id.eq(owner),
+                effective_from.eq(from),
+                daily_time.eq(new_daily),
+                weekly_time.eq(new_weekly),
+                phase_id.eq(owner_phase),
+            ))
+            .execute(conn)
+    });
+    match result {
+        Ok(_) => match phase {
+            Some(p) => println!(
+                "Goals of {} set succesfully during the {} phase.",
+                goal_name(subject),
+                p.name
+            ),
+            None => println!(
+                "Goals of {} set succesfully from {} on.",
+                goal_name(subject),
+                from.format(FORMAT)
+            ),
+        },
+        Err(e) => {
+            eprintln!("Could not set the goal: {e}");
+            process::exit(1);
+        }
+    }
+}
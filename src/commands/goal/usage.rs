@@ -0,0 +1,14 @@
+//! Bad usage display command.
+use crate::FORMAT;
+
+/// Displays the bad usage message from goal command
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} goal ...:\n
+        - set (id/short name/all) [--daily (time)] [--weekly (time)] [--from (date) | --phase (phase name/id)] [--plan (plan id)]: Sets the goals of a subject (or all of them together) from today or the given date on, or only during a phase. Use 'none' to clear a goal.
+        - list [--plan (plan id)]: Lists the goals of the current/specified plan.
+        - remove (goal id): Removes a goal.
+        - report [--plan (plan id)]: Shows how many goals were reached every week.
+        The time format is minutes (90), hours (8h) or both (1h30m). The date format is: {FORMAT}\n\
+    ", crate::env::args().collect::<Vec<String>>().first().unwrap());
+}
@@ -0,0 +1,203 @@
+//! # Credit-weighted average of the marks (GPA).
+//! The gpa command computes, for every period and overall, the average of the final scores of the subjects
+//! weighted by their credits. Subjects without credits are left out of the average, unless none of the subjects
+//! has credits, where the plain average is used instead.
+
+mod usage;
+
+use crate::commands::gpa::usage::display_bad_usage;
+use crate::interpreter::{detect_unknown_arg, get_specific_arg};
+use crate::models::{GradingScale, Period, Subject};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Sums of the credits and marks of a list of subjects.
+#[derive(Default)]
+pub(crate) struct Totals {
+    /// Sum of the credits of the subjects.
+    pub(crate) credits: f32,
+    /// Sum of the credits of the passed subjects.
+    pub(crate) passed_credits: f32,
+    /// Sum of the credits of the marked subjects.
+    graded_credits: f32,
+    /// Sum of the marks weighted by credits.
+    weighted_marks: f32,
+    /// Sum of the marks, and number of marks.
+    marks: (f32, i32),
+    /// Number of marked subjects without credits.
+    uncredited_marks: i32,
+}
+
+impl Totals {
+    /// Adds a subject to the totals.
+    /// # Arguments
+    /// * `subject` - Subject to add.
+    /// * `mark` - Mark of the subject, already converted to the output scale.
+    /// * `passed` - Whether the subject is passed.
+    pub(crate) fn add(&mut self, subject: &Subject, mark: Option<f32>, passed: Option<bool>) {
+        let credits = subject.credits.unwrap_or(0.0);
+        self.credits += credits;
+        if passed == Some(true) {
+            self.passed_credits += credits;
+        }
+        if let Some(mark) = mark {
+            self.graded_credits += credits;
+            self.weighted_marks += mark * credits;
+            if credits <= 0.0 {
+                self.uncredited_marks += 1;
+            }
+            self.marks.0 += mark;
+            self.marks.1 += 1;
+        }
+    }
+
+    /// Merges other totals into these ones.
+    pub(crate) fn merge(&mut self, other: &Totals) {
+        self.credits += other.credits;
+        self.passed_credits += other.passed_credits;
+        self.graded_credits += other.graded_credits;
+        self.weighted_marks += other.weighted_marks;
+        self.marks.0 += other.marks.0;
+        self.marks.1 += other.marks.1;
+        self.uncredited_marks += other.uncredited_marks;
+    }
+
+    /// Gets the average mark, weighted by credits if every marked subject has them (otherwise the marks without
+    /// credits would be left out). Returns None if there are no marks.
+    pub(crate) fn average(&self) -> Option<f32> {
+        if self.graded_credits > 0.0 && self.uncredited_marks == 0 {
+            Some(self.weighted_marks / self.graded_credits)
+        } else if self.marks.1 > 0 {
+            Some(self.marks.0 / self.marks.1 as f32)
+        } else {
+            None
+        }
+    }
+
+    /// Prints the average and the credits.
+    /// # Arguments
+    /// * `label` - Label of the line.
+    /// * `scale` - Grading scale of the average.
+    pub(crate) fn print(&self, label: &str, scale: GradingScale) {
+        match self.average() {
+            Some(average) => {
+                let formatted = match scale {
+                    GradingScale::Letter => {
+                        format!("{:.2} ({})", average, scale.format_score(average))
+                    }
+                    _ => scale.format_score(average),
+                };
+                print!("\t{label}: {formatted} [{scale}]");
+                if self.graded_credits == 0.0 {
+                    print!(" (no credits registered, unweighted)");
+                } else if self.uncredited_marks > 0 {
+                    print!(
+                        " ({} marked subjects without credits, unweighted)",
+                        self.uncredited_marks
+                    );
+                }
+            }
+            None => print!("\t{label}: no marks yet"),
+        }
+        println!(
+            ". Credits: {} graded, {} passed out of {}",
+            self.graded_credits, self.passed_credits, self.credits
+        );
+    }
+}
+
+/// Gets the totals of the subjects of a period.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the subjects.
+/// * `scale` - Output grading scale.
+pub(crate) fn period_totals(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    scale: GradingScale,
+) -> Totals {
+    let mut totals = Totals::default();
+    for s in period.fetch_subjects(conn) {
+        let mark = s.final_score.map(|m| period.scale().convert(m, scale));
+        totals.add(&s, mark, s.is_passed(period.scale()));
+    }
+    totals
+}
+
+/// Prints the subjects of a period and its average.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period to print.
+/// * `scale` - Output grading scale.
+fn print_period(conn: &mut SqliteConnection, period: &Period, scale: GradingScale) {
+    println!("{}", period);
+    for s in period.fetch_subjects(conn) {
+        let mut line = format!("\t * {} ({}): ", s.name, s.short_name);
+        match s.final_score {
+            Some(m) => line.push_str(&scale.format_score(period.scale().convert(m, scale))),
+            None => line.push_str("not marked"),
+        }
+        if let Some(credits) = s.credits {
+            line.push_str(&format!(", {} credits", credits));
+        }
+        match s.is_passed(period.scale()) {
+            Some(true) => line.push_str(", passed"),
+            Some(false) => line.push_str(", failed"),
+            None => {}
+        }
+        println!("{line}");
+    }
+    period_totals(conn, period, scale).print("Average", scale);
+}
+
+/// Displays the credit-weighted averages, based on program args.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `args` - Remaining program arguments.
+pub fn display_gpa(conn: &mut SqliteConnection, args: &mut [String]) {
+    if let Some(o) = detect_unknown_arg(&args.to_vec(), &vec!["--plan", "--scale"], "--") {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let mut periods = match get_specific_arg(args, "--plan") {
+        Some(plan_id) => match plan_id
+            .parse::<i32>()
+            .ok()
+            .and_then(|i| Period::from_id(conn, i))
+        {
+            Some(p) => vec![p],
+            None => {
+                eprintln!("There is no period with the provided id");
+                process::exit(1);
+            }
+        },
+        None => Period::fetch_all_plans(conn),
+    };
+    if periods.is_empty() {
+        println!("No periods created yet.");
+        return;
+    }
+    periods.sort_by_key(|p| p.initial_date);
+    let scale = match get_specific_arg(args, "--scale") {
+        Some(s) => match s.parse::<GradingScale>() {
+            Ok(scale) => Some(scale),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        },
+        None => None,
+    };
+    // Without a given scale, every period uses its own, and the overall average uses the one of the last period.
+    let overall_scale = scale.unwrap_or(periods.last().unwrap().scale());
+    let mut overall = Totals::default();
+    for p in &periods {
+        print_period(conn, p, scale.unwrap_or(p.scale()));
+        overall.merge(&period_totals(conn, p, overall_scale));
+        println!();
+    }
+    if periods.len() > 1 {
+        overall.print("Overall average", overall_scale);
+    }
+}
@@ -0,0 +1,12 @@
+//! GPA module usage
+
+/// Displays the bad usage message of the gpa module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} gpa [--plan (plan id)] [--scale (0-10/0-20/A-F/4.0)]:\n
+        Displays the credit-weighted average of the marks of every plan (or the specified one) and the overall one.\n\
+        With --scale, the marks are converted to the given grading scale.\n\
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
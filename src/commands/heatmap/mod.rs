@@ -0,0 +1,165 @@
+//! # Heatmap of study time.
+//! The heatmap command renders a calendar grid of a whole period (similar to a contribution graph), where every
+//! column is a week, every row is a weekday and the intensity of every cell depends on the time studied that day.
+//! When colors are disabled, the intensity is represented with ASCII characters instead.
+
+mod usage;
+
+use crate::commands::heatmap::usage::display_bad_usage;
+use crate::commands::plan::get_plan_arg;
+use crate::commands::status::WEEKDAY_START;
+use crate::commands::subject::get_plan_subject;
+use crate::interpreter::{detect_unknown_arg, get_specific_arg};
+use crate::models::{Period, Subject};
+use crate::{format_hours_and_minutes, get_terminal_width};
+use colored::control::SHOULD_COLORIZE;
+use colored::{ColoredString, Colorize};
+use diesel::internal::derives::multiconnection::chrono::{Datelike, NaiveDate, TimeDelta};
+use diesel::SqliteConnection;
+use std::collections::HashMap;
+use std::process;
+
+/// Glyph used for every cell when colors are enabled.
+const CELL: &str = "■";
+/// Glyphs used for every intensity level when colors are disabled.
+const ASCII_LEVELS: [&str; 5] = [".", "-", "+", "*", "#"];
+/// Colors (RGB) used for every intensity level above zero.
+const COLOR_LEVELS: [(u8, u8, u8); 4] = [(14, 68, 41), (0, 109, 50), (38, 166, 65), (57, 211, 83)];
+/// Number of columns taken by each cell (glyph and separation).
+const CELL_WIDTH: usize = 2;
+/// Number of columns taken by the weekday labels.
+const LABEL_WIDTH: usize = 4;
+
+/// Gets the intensity level (from 0 to 4) of a day.
+/// # Arguments
+/// * `time` - Time studied that day.
+/// * `max` - Maximum time studied in a single day of the period.
+pub(crate) fn intensity_level(time: i32, max: i32) -> usize {
+    if time <= 0 || max <= 0 {
+        0
+    } else {
+        ((time * 4 + max - 1) / max).clamp(1, 4) as usize
+    }
+}
+
+/// Gets the printable cell of an intensity level.
+/// # Arguments
+/// * `level` - Intensity level, from 0 to 4.
+pub(crate) fn cell(level: usize) -> ColoredString {
+    if !SHOULD_COLORIZE.should_colorize() {
+        return ASCII_LEVELS[level].normal();
+    }
+    match level {
+        0 => CELL.bright_black(),
+        l => {
+            let (r, g, b) = COLOR_LEVELS[l - 1];
+            CELL.truecolor(r, g, b)
+        }
+    }
+}
+
+/// Prints the heatmap of a period.
+/// # Arguments
+/// * `period` - Period to print.
+/// * `days` - Dedicated time of every day with entries.
+fn print_heatmap(period: &Period, days: &HashMap<NaiveDate, i32>) {
+    let max = days.values().copied().max().unwrap_or(0);
+    let first_week = period.initial_date.week(WEEKDAY_START).first_day();
+    let mut weeks: Vec<NaiveDate> = Vec::new();
+    let mut i = first_week;
+    while i <= period.end_or_today() {
+        weeks.push(i);
+        i += TimeDelta::weeks(1);
+    }
+    let available = get_terminal_width().saturating_sub(LABEL_WIDTH) / CELL_WIDTH;
+    if weeks.len() > available && available > 0 {
+        println!("Only the last {} weeks fit in the terminal.", available);
+        weeks.drain(..weeks.len() - available);
+    }
+
+    // Month labels are placed over the first week that contains the first day of the month.
+    let mut labels = " ".repeat(LABEL_WIDTH);
+    for (column, week) in weeks.iter().enumerate() {
+        let month_start = (0..7)
+            .map(|d| *week + TimeDelta::days(d))
+            .find(|d| d.day() == 1);
+        let label = match (column, month_start) {
+            (_, Some(d)) => d.format("%b").to_string(),
+            (0, None) => week.format("%b").to_string(),
+            _ => continue,
+        };
+        let position = LABEL_WIDTH + column * CELL_WIDTH;
+        if labels.len() <= position {
+            labels.push_str(&" ".repeat(position - labels.len()));
+            labels.push_str(&label);
+        }
+    }
+    println!("{labels}");
+
+    for row in 0..7 {
+        let weekday = first_week + TimeDelta::days(row);
+        print!(
+            "{:<width$}",
+            weekday.format("%a").to_string(),
+            width = LABEL_WIDTH
+        );
+        for week in &weeks {
+            let day = *week + TimeDelta::days(row);
+            if day < period.initial_date || day > period.end_date() {
+                print!("{}", " ".repeat(CELL_WIDTH));
+            } else {
+                let level = intensity_level(*days.get(&day).unwrap_or(&0), max);
+                print!("{}{}", cell(level), " ".repeat(CELL_WIDTH - 1));
+            }
+        }
+        println!();
+    }
+
+    println!();
+    print!("{}Less ", " ".repeat(LABEL_WIDTH));
+    for level in 0..5 {
+        print!("{} ", cell(level));
+    }
+    println!("More (max: {} in a day)", format_hours_and_minutes(max));
+}
+
+/// Displays the heatmap of a period, based on program args.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `args` - Remaining program arguments.
+pub fn display_heatmap(conn: &mut SqliteConnection, args: &mut Vec<String>) {
+    let plan_id = get_plan_arg(args, conn);
+    if let Some(o) = detect_unknown_arg(args, &vec!["--subject"], "--") {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let period = match Period::from_id(conn, plan_id) {
+        Some(period) => period,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    };
+    let subject: Option<Subject> = match args.contains(&"--subject".to_string()) {
+        true => match get_specific_arg(args, "--subject") {
+            Some(s) => Some(get_plan_subject(&s, conn, plan_id)),
+            None => {
+                display_bad_usage();
+                process::exit(1);
+            }
+        },
+        false => None,
+    };
+    let days = period.daily_dedicated_time(conn, subject.as_ref());
+    match &subject {
+        Some(s) => println!("Plan: {} (ID:{}) - {}", period.description, period.id, s),
+        None => println!("Plan: {} (ID:{})", period.description, period.id),
+    }
+    println!(
+        "You have studied {} in {} different days.\n",
+        format_hours_and_minutes(days.values().sum()),
+        days.values().filter(|t| **t > 0).count()
+    );
+    print_heatmap(&period, &days);
+}
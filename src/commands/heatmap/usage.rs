@@ -0,0 +1,11 @@
+//! Heatmap module usage
+
+/// Displays the bad usage message of the heatmap module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} heatmap [--plan (plan id)] [--subject (id/short name)]:\n
+        Displays a calendar of the current/specified plan, where the intensity of every day depends on the time studied.\n\
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
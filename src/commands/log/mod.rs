@@ -0,0 +1,112 @@
+//! # Log of entries.
+//! The log command lists the raw entries of a plan (optionally of a single subject and between two dates) in a table
+//! with their ids, tags and notes, so they can be fixed with `entry edit` and `entry delete`.
+
+mod usage;
+
+use crate::commands::log::usage::display_bad_usage;
+use crate::commands::plan::get_plan_arg;
+use crate::commands::subject::get_plan_subject;
+use crate::interpreter::{detect_unknown_arg, get_specific_arg, parse_date};
+use crate::models::{Entry, Period, Subject};
+use crate::{format_hours_and_minutes, FORMAT};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Prints a table of entries, with their activity types, focus, tags and notes.
+/// # Arguments
+/// * `entries` - Entries to print.
+/// * `subject_list` - Subjects of the entries.
+pub(crate) fn print_entries(entries: &[Entry], subject_list: &[Subject]) {
+    let id_width = entries
+        .iter()
+        .map(|e| e.id.to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max("ID".len());
+    let subject_width = subject_list
+        .iter()
+        .map(|s| s.short_name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Subject".len());
+    let time_width = entries
+        .iter()
+        .map(|e| format_hours_and_minutes(e.dedicated_time).len())
+        .max()
+        .unwrap_or(0)
+        .max("Time".len());
+    let type_width = entries
+        .iter()
+        .map(|e| e.activity.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Type".len());
+    println!(
+        "\t{:>id_width$}  {:<10}  {:<subject_width$}  {:<time_width$}  {:<type_width$}  Focus  Tags and note",
+        "ID", "Date", "Subject", "Time", "Type"
+    );
+    for e in entries {
+        let subject = subject_list.iter().find(|s| s.id == e.subject_id).unwrap();
+        let mut annotations: Vec<String> = e.tag_list().iter().map(|t| format!("#{t}")).collect();
+        if let Some(note) = &e.note {
+            annotations.push(note.clone());
+        }
+        println!(
+            "\t{:>id_width$}  {}  {:<subject_width$}  {:<time_width$}  {:<type_width$}  {:<5}  {}",
+            e.id,
+            e.date.format(FORMAT),
+            subject.paint(&subject.short_name),
+            format_hours_and_minutes(e.dedicated_time),
+            e.activity,
+            e.focus
+                .map(|f| format!("{f:.1}"))
+                .unwrap_or(String::from("-")),
+            annotations.join(" ")
+        );
+    }
+}
+
+/// Displays the entries of a plan, based on program args.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `args` - Remaining program arguments.
+pub fn display_log(conn: &mut SqliteConnection, args: &mut Vec<String>) {
+    let plan_id = get_plan_arg(args, conn);
+    if let Some(o) = detect_unknown_arg(args, &vec!["--from", "--to", "--subject"], "--") {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let period = match Period::from_id(conn, plan_id) {
+        Some(period) => period,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    };
+    let first = get_specific_arg(args, "--from").map(|d| parse_date(d.trim()));
+    let last = get_specific_arg(args, "--to").map(|d| parse_date(d.trim()));
+    let subject_list = match get_specific_arg(args, "--subject") {
+        Some(s) => vec![get_plan_subject(&s, conn, plan_id)],
+        None => period.fetch_subjects(conn),
+    };
+    let ids: Vec<i32> = subject_list.iter().map(|s| s.id).collect();
+    let entries = Entry::fetch_by_subjects(conn, &ids, (first, last));
+    match subject_list.as_slice() {
+        [s] if args.contains(&"--subject".to_string()) => {
+            println!("Plan: {} (ID:{}) - {}", period.description, period.id, s)
+        }
+        _ => println!("Plan: {} (ID:{})", period.description, period.id),
+    }
+    if entries.is_empty() {
+        println!("No entries found.");
+        return;
+    }
+    print_entries(&entries, &subject_list);
+    println!(
+        "{} entries, {} in total.",
+        entries.len(),
+        format_hours_and_minutes(entries.iter().map(|e| e.dedicated_time).sum())
+    );
+}
@@ -0,0 +1,13 @@
+//! Log module usage
+use crate::FORMAT;
+
+/// Displays the bad usage message of the log module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} log [--from (date)] [--to (date)] [--subject (id/short name)] [--plan (plan id)]:\n
+        Lists the entries of the current/specified plan with their ids, which can be used with `entry edit` and `entry delete`.
+        The date format is: {FORMAT}\n\
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
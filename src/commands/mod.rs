@@ -1,6 +1,17 @@
 //! Handles all avaliable commands
+pub mod activity;
+pub mod analyze;
+pub mod chart;
+pub mod config;
 pub mod entry;
 pub mod export;
+pub mod goal;
+pub mod gpa;
+pub mod heatmap;
+pub mod log;
 pub mod plan;
+pub mod pomodoro;
+pub mod program;
+pub mod search;
 pub mod status;
 pub mod subject;
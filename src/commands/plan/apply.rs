@@ -0,0 +1,516 @@
+//! Module for applying declarative plan files.
+//!
+//! A plan file is a TOML file that describes a period, its subjects, their exams and the goals in effect from
+//! the start of the period:
+//! ```toml
+//! description = "Fall 2027"
+//! start = "01-09-2027"
+//! end = "31-01-2028"
+//! scale = "0-10"
+//! goals = { weekly = "20h" }
+//!
+//! [[subjects]]
+//! short_name = "Dbs"
+//! name = "Databases"
+//! credits = 6
+//! category = "core"
+//! goals = { daily = "1h", weekly = "8h" }
+//! exams = [{ date = "15-12-2027", kind = "final", weight = 60 }]
+//! ```
+//! Leaving the end date out makes the period open-ended, and `overlap = true` allows it to overlap other periods.
+//! `parent = "Dbs"` makes a subject a child of another subject of the file.
+//! The period is identified by its description, subjects by their short name and exams by their date and kind, so
+//! applying the same file twice doesn't change anything. Optional fields left out of the file keep their current
+//! value (except for the end date), and nothing that is missing from the file is removed.
+
+use crate::interpreter::parse_duration;
+use crate::models::{Exam, Goal, GradingScale, Period, Subject};
+use crate::schema::{exams, goals, periods, subjects};
+use crate::FORMAT;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::{
+    delete, insert_into, update, Connection, ExpressionMethods, QueryDsl, RunQueryDsl,
+    SqliteConnection,
+};
+use serde::Deserialize;
+use std::{fs, process};
+
+/// Plan file, as written by the user.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PlanFile {
+    description: String,
+    start: String,
+    end: Option<String>,
+    #[serde(default)]
+    overlap: bool,
+    scale: Option<String>,
+    goals: Option<GoalSpec>,
+    #[serde(default)]
+    subjects: Vec<SubjectSpec>,
+}
+
+/// Subject of a plan file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SubjectSpec {
+    short_name: String,
+    name: String,
+    credits: Option<f32>,
+    category: Option<String>,
+    parent: Option<String>,
+    goals: Option<GoalSpec>,
+    #[serde(default)]
+    exams: Vec<ExamSpec>,
+}
+
+/// Exam of a subject of a plan file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExamSpec {
+    date: String,
+    kind: String,
+    weight: Option<f32>,
+    description: Option<String>,
+}
+
+/// Goals of a plan file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GoalSpec {
+    daily: Option<TimeSpec>,
+    weekly: Option<TimeSpec>,
+}
+
+/// Time of a goal, either in minutes or as a duration (e.g.: "1h30m").
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TimeSpec {
+    Minutes(i32),
+    Duration(String),
+}
+
+/// Validated goals, in minutes. None means the value isn't managed by the file.
+#[derive(Clone, Copy)]
+struct Goals {
+    daily: Option<i32>,
+    weekly: Option<i32>,
+}
+
+/// Validated exam.
+struct ExamPlan {
+    date: NaiveDate,
+    kind: String,
+    weight: Option<f32>,
+    description: Option<String>,
+}
+
+/// Validated subject.
+struct SubjectPlan {
+    short_name: String,
+    name: String,
+    credits: Option<f32>,
+    category: Option<String>,
+    parent: Option<String>,
+    goals: Option<Goals>,
+    exams: Vec<ExamPlan>,
+}
+
+/// Validated plan file.
+struct Plan {
+    description: String,
+    start: NaiveDate,
+    end: Option<NaiveDate>,
+    overlap: bool,
+    scale: Option<GradingScale>,
+    goals: Option<Goals>,
+    subjects: Vec<SubjectPlan>,
+}
+
+/// Parses a date of a plan file.
+/// # Arguments
+/// * `value` - Date to parse.
+fn parse_file_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value.trim(), FORMAT)
+        .map_err(|_| format!("Invalid date '{value}'. Remember using format '{FORMAT}'"))
+}
+
+/// Validates the goals of a plan file.
+/// # Arguments
+/// * `spec` - Goals to validate.
+fn validate_goals(spec: &GoalSpec) -> Result<Goals, String> {
+    let minutes = |t: &Option<TimeSpec>| match t {
+        None => Ok(None),
+        Some(TimeSpec::Minutes(m)) if *m >= 0 => Ok(Some(*m)),
+        Some(TimeSpec::Minutes(m)) => Err(format!("Invalid goal time '{m}'")),
+        Some(TimeSpec::Duration(d)) => match parse_duration(d) {
+            Some(m) => Ok(Some(m)),
+            None => Err(format!(
+                "Invalid goal time '{d}'. Use minutes (90), hours (8h) or both (1h30m)."
+            )),
+        },
+    };
+    Ok(Goals {
+        daily: minutes(&spec.daily)?,
+        weekly: minutes(&spec.weekly)?,
+    })
+}
+
+/// Validates a plan file, checking its dates, times and grading scale.
+/// # Arguments
+/// * `file` - Plan file to validate.
+fn validate(file: PlanFile) -> Result<Plan, String> {
+    let start = parse_file_date(&file.start)?;
+    let end = match &file.end {
+        Some(e) => Some(parse_file_date(e)?),
+        None => None,
+    };
+    if end.is_some_and(|e| start > e) {
+        return Err(String::from("Start date can't be after end date"));
+    }
+    let scale = match &file.scale {
+        Some(s) => Some(s.parse::<GradingScale>()?),
+        None => None,
+    };
+    let mut plan_subjects: Vec<SubjectPlan> = Vec::new();
+    for s in file.subjects {
+        if s.short_name.parse::<i32>().is_ok() {
+            return Err(format!("Short name '{}' can't be a number", s.short_name));
+        }
+        if plan_subjects.iter().any(|p| p.short_name == s.short_name) {
+            return Err(format!("Subject '{}' is repeated", s.short_name));
+        }
+        if s.credits.is_some_and(|c| c < 0.0) {
+            return Err(format!("Credits of '{}' can't be negative", s.short_name));
+        }
+        let mut plan_exams = Vec::new();
+        for e in s.exams {
+            let date = parse_file_date(&e.date)?;
+            if date < start || end.is_some_and(|e| date > e) {
+                return Err(format!(
+                    "The exam of '{}' on {} must be inside the period",
+                    s.short_name, e.date
+                ));
+            }
+            plan_exams.push(ExamPlan {
+                date,
+                kind: e.kind,
+                weight: e.weight,
+                description: e.description,
+            });
+        }
+        plan_subjects.push(SubjectPlan {
+            goals: s.goals.as_ref().map(validate_goals).transpose()?,
+            short_name: s.short_name,
+            name: s.name,
+            credits: s.credits,
+            category: s.category.map(|c| c.trim().to_lowercase()),
+            parent: s.parent,
+            exams: plan_exams,
+        });
+    }
+    for s in &plan_subjects {
+        // Parents are followed up to the number of subjects, since a longer chain must be a loop.
+        let mut current = s;
+        for _ in 0..plan_subjects.len() {
+            let parent = match &current.parent {
+                Some(p) => p,
+                None => break,
+            };
+            current = match plan_subjects.iter().find(|p| &p.short_name == parent) {
+                Some(p) if p.short_name == s.short_name => {
+                    return Err(format!(
+                        "Subject '{}' can't be its own ancestor",
+                        s.short_name
+                    ))
+                }
+                Some(p) => p,
+                None => {
+                    return Err(format!(
+                        "The parent of '{}' must be a subject of the file",
+                        current.short_name
+                    ))
+                }
+            };
+        }
+    }
+    Ok(Plan {
+        goals: file.goals.as_ref().map(validate_goals).transpose()?,
+        description: file.description,
+        start,
+        end,
+        overlap: file.overlap,
+        scale,
+        subjects: plan_subjects,
+    })
+}
+
+/// Sets the goals of a subject (or the whole period) in effect from the start of the period, unless they are
+/// already set. Returns whether anything changed.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `plan_id` - Id of the period.
+/// * `start` - Start of the period.
+/// * `owner` - Id of the subject, where None means the whole period.
+/// * `new_goals` - Goals to set.
+fn apply_goals(
+    conn: &mut SqliteConnection,
+    plan_id: i32,
+    start: NaiveDate,
+    owner: Option<i32>,
+    new_goals: Goals,
+) -> Result<bool, diesel::result::Error> {
+    let current = Goal::fetch_by_period(conn, plan_id)
+        .into_iter()
+        .find(|g| g.subject_id == owner && g.effective_from == start && g.phase_id.is_none());
+    let daily = new_goals
+        .daily
+        .or(current.as_ref().and_then(|g| g.daily_time));
+    let weekly = new_goals
+        .weekly
+        .or(current.as_ref().and_then(|g| g.weekly_time));
+    if let Some(g) = current {
+        if g.daily_time == daily && g.weekly_time == weekly {
+            return Ok(false);
+        }
+        delete(goals::table.filter(goals::id.eq(g.id))).execute(conn)?;
+    }
+    insert_into(goals::table)
+        .values((
+            goals::period_id.eq(plan_id),
+            goals::subject_id.eq(owner),
+            goals::effective_from.eq(start),
+            goals::daily_time.eq(daily),
+            goals::weekly_time.eq(weekly),
+        ))
+        .execute(conn)?;
+    Ok(true)
+}
+
+/// Applies a validated plan, creating or updating the period, its subjects, exams and goals. Returns the list of
+/// changes.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `plan` - Plan to apply.
+/// * `existing` - Period with the same description, if any.
+fn apply_plan(
+    conn: &mut SqliteConnection,
+    plan: &Plan,
+    existing: Option<Period>,
+) -> Result<Vec<String>, diesel::result::Error> {
+    let mut changes: Vec<String> = Vec::new();
+    let plan_id = match &existing {
+        Some(p) => {
+            let scale = plan.scale.unwrap_or(p.scale());
+            if p.initial_date != plan.start || p.final_date != plan.end || p.scale() != scale {
+                // Marks are kept in the grading scale of their period, so they are converted if the scale changes.
+                if p.scale() != scale {
+                    for s in p.fetch_subjects(conn) {
+                        if let Some(score) = s.final_score {
+                            update(subjects::table.filter(subjects::id.eq(s.id)))
+                                .set(subjects::final_score.eq(p.scale().convert(score, scale)))
+                                .execute(conn)?;
+                        }
+                    }
+                }
+                update(periods::table.filter(periods::id.eq(p.id)))
+                    .set((
+                        periods::initial_date.eq(plan.start),
+                        periods::final_date.eq(plan.end),
+                        periods::grading_scale.eq(scale.to_string()),
+                    ))
+                    .execute(conn)?;
+                changes.push(format!("~ plan {}", plan.description));
+            }
+            p.id
+        }
+        None => {
+            changes.push(format!("+ plan {}", plan.description));
+            insert_into(periods::table)
+                .values((
+                    periods::initial_date.eq(plan.start),
+                    periods::final_date.eq(plan.end),
+                    periods::description.eq(&plan.description),
+                    periods::grading_scale.eq(plan.scale.unwrap_or(GradingScale::Ten).to_string()),
+                ))
+                .returning(periods::id)
+                .get_result::<i32>(conn)?
+        }
+    };
+    if let Some(g) = plan.goals {
+        if apply_goals(conn, plan_id, plan.start, None, g)? {
+            changes.push(String::from("~ goals of all subjects"));
+        }
+    }
+    let current_subjects = subjects::table
+        .filter(subjects::period_id.eq(plan_id))
+        .load::<Subject>(conn)?;
+    for s in &plan.subjects {
+        let subject_id = match current_subjects
+            .iter()
+            .find(|c| c.short_name == s.short_name)
+        {
+            Some(c) => {
+                let new_credits = s.credits.or(c.credits);
+                let new_category = s.category.clone().or(c.category.clone());
+                if c.name != s.name || c.credits != new_credits || c.category != new_category {
+                    update(subjects::table.filter(subjects::id.eq(c.id)))
+                        .set((
+                            subjects::name.eq(&s.name),
+                            subjects::credits.eq(new_credits),
+                            subjects::category.eq(new_category),
+                        ))
+                        .execute(conn)?;
+                    changes.push(format!("~ subject {}", s.short_name));
+                }
+                c.id
+            }
+            None => {
+                changes.push(format!("+ subject {}", s.short_name));
+                insert_into(subjects::table)
+                    .values((
+                        subjects::period_id.eq(plan_id),
+                        subjects::short_name.eq(&s.short_name),
+                        subjects::name.eq(&s.name),
+                        subjects::credits.eq(s.credits),
+                        subjects::category.eq(&s.category),
+                        subjects::color.eq(Subject::next_color(conn, plan_id)),
+                    ))
+                    .returning(subjects::id)
+                    .get_result::<i32>(conn)?
+            }
+        };
+        if let Some(g) = s.goals {
+            if apply_goals(conn, plan_id, plan.start, Some(subject_id), g)? {
+                changes.push(format!("~ goals of {}", s.short_name));
+            }
+        }
+        let current_exams = Exam::fetch_by_subject(conn, subject_id);
+        for e in &s.exams {
+            let label = format!(
+                "exam {} of {} on {}",
+                e.kind,
+                s.short_name,
+                e.date.format(FORMAT)
+            );
+            match current_exams
+                .iter()
+                .find(|c| c.date == e.date && c.kind.eq_ignore_ascii_case(&e.kind))
+            {
+                Some(c) => {
+                    let new_weight = e.weight.or(c.weight);
+                    let new_description = e.description.clone().or(c.description.clone());
+                    if c.weight != new_weight || c.description != new_description {
+                        update(exams::table.filter(exams::id.eq(c.id)))
+                            .set((
+                                exams::weight.eq(new_weight),
+                                exams::description.eq(new_description),
+                            ))
+                            .execute(conn)?;
+                        changes.push(format!("~ {label}"));
+                    }
+                }
+                None => {
+                    insert_into(exams::table)
+                        .values((
+                            exams::subject_id.eq(subject_id),
+                            exams::date.eq(e.date),
+                            exams::kind.eq(&e.kind),
+                            exams::weight.eq(e.weight),
+                            exams::description.eq(&e.description),
+                        ))
+                        .execute(conn)?;
+                    changes.push(format!("+ {label}"));
+                }
+            }
+        }
+    }
+    // Parents are set once every subject of the file exists.
+    let current_subjects = subjects::table
+        .filter(subjects::period_id.eq(plan_id))
+        .load::<Subject>(conn)?;
+    let id_of = |short: &str| {
+        current_subjects
+            .iter()
+            .find(|c| c.short_name == short)
+            .map(|c| c.id)
+    };
+    for s in &plan.subjects {
+        let (Some(subject_id), Some(new_parent)) =
+            (id_of(&s.short_name), s.parent.as_deref().and_then(id_of))
+        else {
+            continue;
+        };
+        if current_subjects
+            .iter()
+            .any(|c| c.id == subject_id && c.parent_id != Some(new_parent))
+        {
+            update(subjects::table.filter(subjects::id.eq(subject_id)))
+                .set(subjects::parent_id.eq(new_parent))
+                .execute(conn)?;
+            changes.push(format!("~ parent of {}", s.short_name));
+        }
+    }
+    Ok(changes)
+}
+
+/// Creates or updates a plan from a plan file.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `path` - Path of the plan file.
+pub fn apply(conn: &mut SqliteConnection, path: &str) {
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Failed to read '{path}': {e}");
+            process::exit(1);
+        }
+    };
+    let plan = match toml::from_str::<PlanFile>(&content)
+        .map_err(|e| e.to_string())
+        .and_then(validate)
+    {
+        Ok(plan) => plan,
+        Err(e) => {
+            eprintln!("Invalid plan file '{path}': {e}");
+            process::exit(1);
+        }
+    };
+    let all_plans = Period::fetch_all_plans(conn);
+    let mut same_description = all_plans
+        .iter()
+        .filter(|p| p.description == plan.description);
+    let existing = same_description.next().cloned();
+    if same_description.next().is_some() {
+        eprintln!(
+            "There is more than one plan described as '{}'. Rename one of them first.",
+            plan.description
+        );
+        process::exit(1);
+    }
+    for p in &all_plans {
+        if Some(p.id) != existing.as_ref().map(|e| e.id)
+            && !plan.overlap
+            && p.overlaps((plan.start, plan.end.unwrap_or(NaiveDate::MAX)))
+        {
+            eprintln!("The plan cannot overlap another period (set `overlap = true` to allow it).");
+            eprintln!("Overlapped period: {}", p);
+            process::exit(1);
+        }
+    }
+    match conn.transaction(|conn| apply_plan(conn, &plan, existing)) {
+        Ok(changes) if changes.is_empty() => {
+            println!("The plan is already up to date.");
+        }
+        Ok(changes) => {
+            for c in changes {
+                println!("\t{c}");
+            }
+            println!("Plan applied succesfully");
+        }
+        Err(e) => {
+            eprintln!("Failed to apply the plan: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Handles break adding.
+use crate::models::Period;
+use crate::schema::breaks::dsl::breaks;
+use crate::schema::breaks::{end_date, label, period_id, start_date};
+use diesel::dsl::insert_into;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::ExpressionMethods;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Inserts a break into the database, without any check.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the break.
+/// * `start` - First day of the break.
+/// * `end` - Last day of the break.
+/// * `new_label` - Label of the break (e.g.: Christmas).
+pub(super) fn insert(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    start: NaiveDate,
+    end: NaiveDate,
+    new_label: String,
+) {
+    if let Err(e) = insert_into(breaks)
+        .values((
+            period_id.eq(period.id),
+            start_date.eq(start),
+            end_date.eq(end),
+            label.eq(new_label),
+        ))
+        .execute(conn)
+    {
+        eprintln!("Could not insert break into database: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Adds a new break to a period.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the break.
+/// * `start` - First day of the break.
+/// * `end` - Last day of the break.
+/// * `new_label` - Label of the break (e.g.: Christmas).
+pub fn add(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    start: NaiveDate,
+    end: NaiveDate,
+    new_label: String,
+) {
+    if start > end {
+        eprintln!("The start of the break must be before its end.");
+        process::exit(1);
+    }
+    if start < period.initial_date || end > period.end_date() {
+        eprintln!("The break must be inside the period.");
+        process::exit(1);
+    }
+    insert(conn, period, start, end, new_label);
+    println!("Break added succesfully");
+}
@@ -0,0 +1,146 @@
+//! Handles the import of breaks from iCalendar (.ics) files, where every event is imported as a break.
+use crate::commands::plan::breaks::add::insert;
+use crate::models::{Break, Period};
+use crate::FORMAT;
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, TimeDelta};
+use diesel::SqliteConnection;
+use std::{fs, process};
+
+/// Event of an iCalendar file.
+struct Event {
+    start: NaiveDate,
+    end: NaiveDate,
+    summary: String,
+}
+
+/// Joins the folded lines of an iCalendar file (lines starting with a space or a tab continue the previous one).
+/// # Arguments
+/// * `content` - Content of the file.
+fn unfold(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Parses an iCalendar date (`20261221`) or date-time (`20261221T090000Z`). Returns the date and whether it is
+/// the start of the day, or None if it isn't valid.
+/// # Arguments
+/// * `value` - Value to parse.
+fn parse_ics_date(value: &str) -> Option<(NaiveDate, bool)> {
+    let day = NaiveDate::parse_from_str(value.get(..8)?, "%Y%m%d").ok()?;
+    let midnight = match value.get(8..) {
+        None | Some("") => true,
+        Some(time) => time.trim_start_matches('T').starts_with("000000"),
+    };
+    Some((day, midnight))
+}
+
+/// Gets the events of an iCalendar file.
+/// # Arguments
+/// * `content` - Content of the file.
+fn parse_events(content: &str) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut start: Option<NaiveDate> = None;
+    let mut end: Option<(NaiveDate, bool)> = None;
+    let mut summary = String::new();
+    for line in unfold(content) {
+        let (name, value) = match line.split_once(':') {
+            Some((name, value)) => (
+                name.split(';').next().unwrap_or("").to_uppercase(),
+                value.trim(),
+            ),
+            None => continue,
+        };
+        match (name.as_str(), value.to_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") => {
+                start = None;
+                end = None;
+                summary.clear();
+            }
+            ("DTSTART", _) => start = parse_ics_date(value).map(|(d, _)| d),
+            ("DTEND", _) => end = parse_ics_date(value),
+            ("SUMMARY", _) => {
+                summary = value
+                    .replace("\\n", " ")
+                    .replace("\\N", " ")
+                    .replace("\\,", ",")
+                    .replace("\\;", ";")
+                    .replace("\\\\", "\\")
+            }
+            ("END", "VEVENT") => {
+                if let Some(start) = start {
+                    // The end of an event is exclusive, so an event ending at the start of a day doesn't include it.
+                    let end = match end {
+                        Some((d, true)) => d - TimeDelta::days(1),
+                        Some((d, false)) => d,
+                        None => start,
+                    };
+                    events.push(Event {
+                        start,
+                        end: end.max(start),
+                        summary: match summary.trim() {
+                            "" => String::from("Break"),
+                            s => s.to_string(),
+                        },
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    events
+}
+
+/// Imports the events of an iCalendar file as breaks of a period. Events are clipped to the period, and the ones
+/// outside of it or already imported are skipped.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the breaks.
+/// * `path` - Path of the iCalendar file.
+pub fn import(conn: &mut SqliteConnection, period: &Period, path: &str) {
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Failed to read '{path}': {e}");
+            process::exit(1);
+        }
+    };
+    let events = parse_events(&content);
+    if events.is_empty() {
+        println!("No events found in '{path}'");
+        return;
+    }
+    let existing = Break::fetch_by_period(conn, period.id);
+    let (mut imported, mut outside, mut repeated) = (0, 0, 0);
+    for event in events {
+        if event.end < period.initial_date || event.start > period.end_date() {
+            outside += 1;
+            continue;
+        }
+        let start = event.start.max(period.initial_date);
+        let end = event.end.min(period.end_date());
+        if existing
+            .iter()
+            .any(|b| b.start_date == start && b.end_date == end && b.label == event.summary)
+        {
+            repeated += 1;
+            continue;
+        }
+        println!(
+            "\t+ {} - {}\t{}",
+            start.format(FORMAT),
+            end.format(FORMAT),
+            event.summary
+        );
+        insert(conn, period, start, end, event.summary);
+        imported += 1;
+    }
+    println!(
+        "Imported {imported} breaks ({outside} outside the period, {repeated} already imported)."
+    );
+}
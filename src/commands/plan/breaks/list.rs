@@ -0,0 +1,19 @@
+//! Handles break listing.
+use crate::models::{Break, Period};
+use diesel::SqliteConnection;
+
+/// Lists the breaks of a period.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the breaks.
+pub fn list(conn: &mut SqliteConnection, period: &Period) {
+    let period_breaks = Break::fetch_by_period(conn, period.id);
+    if period_breaks.is_empty() {
+        println!("No breaks from this period");
+        return;
+    }
+    println!("Breaks of {} (ID:{}):", period.description, period.id);
+    for b in period_breaks {
+        println!("\t{b}");
+    }
+}
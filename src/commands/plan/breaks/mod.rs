@@ -0,0 +1,70 @@
+//! Handles commands related to the breaks of a plan: non-teaching date ranges (holidays, exam-only weeks...) that
+//! are left out of the weekly averages and labeled in the week comparisons.
+
+mod add;
+mod import;
+mod list;
+mod remove;
+
+use crate::commands::plan::get_plan_arg;
+use crate::commands::plan::usage::display_bad_usage;
+use crate::debug_println;
+use crate::interpreter::parse_date;
+use crate::models::Period;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Interprets break subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    let plan_id = get_plan_arg(args, conn);
+    let period = match Period::from_id(conn, plan_id) {
+        Some(period) => period,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    };
+    match option.trim() {
+        "add" => {
+            if args.len() < 3 {
+                display_bad_usage();
+                process::exit(1);
+            }
+            let start = parse_date(args[0].trim());
+            let end = parse_date(args[1].trim());
+            let label = args.split_at(2).1.join(" ");
+            add::add(conn, &period, start, end, label);
+        }
+        "list" => {
+            list::list(conn, &period);
+        }
+        "remove" => match args.first().map(|i| i.parse::<i32>()) {
+            Some(Ok(break_id)) => remove::remove(conn, break_id),
+            _ => {
+                display_bad_usage();
+                process::exit(1);
+            }
+        },
+        "import" => match args.first() {
+            Some(path) => import::import(conn, &period, path),
+            None => {
+                display_bad_usage();
+                process::exit(1);
+            }
+        },
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
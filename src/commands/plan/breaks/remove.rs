@@ -0,0 +1,27 @@
+//! Handles break removal.
+use crate::schema::breaks::dsl::breaks;
+use crate::schema::breaks::id;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Removes an existing break.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `break_id` - Break's id.
+pub fn remove(conn: &mut SqliteConnection, break_id: i32) {
+    match delete(breaks.filter(id.eq(break_id))).execute(conn) {
+        Ok(0) => {
+            eprintln!("There is no break with id {break_id}");
+            process::exit(1);
+        }
+        Ok(_) => {
+            println!("Break removed succesfully");
+        }
+        Err(e) => {
+            eprintln!("Error deleting break: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Module for cloning periods with their subjects.
+
+use crate::models::{Goal, Period};
+use crate::schema::goals::dsl::goals;
+use crate::schema::periods::dsl::periods;
+use crate::schema::periods::{description, final_date, grading_scale, initial_date};
+use crate::schema::subjects::dsl::subjects;
+use crate::schema::subjects::{category, color, credits, name, parent_id, period_id, short_name};
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::SqliteConnection;
+use diesel::{delete, insert_into, update, Connection, ExpressionMethods, QueryDsl, RunQueryDsl};
+use std::collections::HashMap;
+use std::process;
+
+/// Creates a new period with the subjects of another one, but without their entries nor marks.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `source` - Period to clone.
+/// * `new_start` - Start date of the new period.
+/// * `new_end` - End date of the new period. If None, the new period is open-ended.
+/// * `new_description` - Description of the new period.
+/// * `with_goals` - Whether to copy the goals too, shifted to the new dates. Phase goals aren't copied.
+/// * `with_credits` - Whether to copy the credits of the subjects too.
+/// * `allow_overlap` - Whether the new period may overlap other periods.
+#[allow(clippy::too_many_arguments)]
+pub fn clone_plan(
+    conn: &mut SqliteConnection,
+    source: &Period,
+    new_start: NaiveDate,
+    new_end: Option<NaiveDate>,
+    new_description: String,
+    with_goals: bool,
+    with_credits: bool,
+    allow_overlap: bool,
+) {
+    if new_end.is_some_and(|end| new_start.gt(&end)) {
+        eprintln!("Invalid arguments: Start date can't be after end date");
+        process::exit(1);
+    }
+    for p in Period::fetch_all_plans(conn) {
+        if !allow_overlap && p.overlaps((new_start, new_end.unwrap_or(NaiveDate::MAX))) {
+            eprintln!("Invalid state: The new period overlaps another period (use --overlap to allow it).");
+            eprintln!("Overlapped period: {}", p);
+            process::exit(1);
+        }
+    }
+    let source_subjects = source.fetch_subjects(conn);
+    let source_goals = match with_goals {
+        true => Goal::fetch_by_period(conn, source.id),
+        false => Vec::new(),
+    };
+    let offset = new_start - source.initial_date;
+    let result = conn.transaction(|conn| {
+        let new_id = insert_into(periods)
+            .values((
+                initial_date.eq(new_start),
+                final_date.eq(new_end),
+                description.eq(&new_description),
+                grading_scale.eq(&source.grading_scale),
+            ))
+            .returning(crate::schema::periods::id)
+            .get_result::<i32>(conn)?;
+        let mut new_subjects: HashMap<i32, i32> = HashMap::new();
+        for s in &source_subjects {
+            let new_subject = insert_into(subjects)
+                .values((
+                    period_id.eq(new_id),
+                    short_name.eq(&s.short_name),
+                    name.eq(&s.name),
+                    credits.eq(s.credits.filter(|_| with_credits)),
+                    color.eq(&s.color),
+                    category.eq(&s.category),
+                ))
+                .returning(crate::schema::subjects::id)
+                .get_result::<i32>(conn)?;
+            new_subjects.insert(s.id, new_subject);
+        }
+        // Parents are set once every subject has its new id.
+        for s in &source_subjects {
+            if let Some(parent) = s.parent_id.and_then(|p| new_subjects.get(&p)) {
+                update(subjects.filter(crate::schema::subjects::id.eq(new_subjects[&s.id])))
+                    .set(parent_id.eq(parent))
+                    .execute(conn)?;
+            }
+        }
+        let mut skipped = 0;
+        for g in &source_goals {
+            if g.phase_id.is_some() {
+                skipped += 1;
+                continue;
+            }
+            // Goals are shifted as the period, and the ones falling outside of it are moved to its limits, where
+            // the latest one wins.
+            let from =
+                (g.effective_from + offset).clamp(new_start, new_end.unwrap_or(NaiveDate::MAX));
+            let owner = g.subject_id.and_then(|s| new_subjects.get(&s).copied());
+            let mut replaced = delete(goals)
+                .filter(crate::schema::goals::period_id.eq(new_id))
+                .filter(crate::schema::goals::effective_from.eq(from))
+                .into_boxed();
+            replaced = match owner {
+                Some(s) => replaced.filter(crate::schema::goals::subject_id.eq(s)),
+                None => replaced.filter(crate::schema::goals::subject_id.is_null()),
+            };
+            replaced.execute(conn)?;
+            insert_into(goals)
+                .values((
+                    crate::schema::goals::period_id.eq(new_id),
+                    crate::schema::goals::subject_id.eq(owner),
+                    crate::schema::goals::effective_from.eq(from),
+                    crate::schema::goals::daily_time.eq(g.daily_time),
+                    crate::schema::goals::weekly_time.eq(g.weekly_time),
+                ))
+                .execute(conn)?;
+        }
+        Ok::<(i32, i32), diesel::result::Error>((new_id, skipped))
+    });
+    match result {
+        Ok((new_id, skipped)) => {
+            println!(
+                "Plan cloned succesfully with {} subjects (ID:{new_id})",
+                source_subjects.len()
+            );
+            if skipped > 0 {
+                println!("{skipped} phase goals weren't copied, since phases aren't cloned.");
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to clone the plan: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,16 +1,20 @@
 //! Module for periods (or plans) management.
 
+mod apply;
+mod breaks;
+mod clone;
 mod list;
 mod modify;
+mod phases;
 mod remove;
 mod start;
 mod usage;
 
 use crate::commands::plan::usage::display_bad_usage;
-use crate::interpreter::{get_specific_arg, parse_date, request_confirmation};
-use crate::models::Period;
-use crate::{debug_println, FORMAT};
-use diesel::internal::derives::multiconnection::chrono::{Local, NaiveDate};
+use crate::interpreter::{detect_unknown_arg, get_specific_arg, parse_date, request_confirmation};
+use crate::models::{GradingScale, Period, Phase};
+use crate::{debug_println, today, FORMAT};
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, ParseError};
 use diesel::SqliteConnection;
 use std::process;
 
@@ -59,6 +63,50 @@ pub fn get_date_arg(args: &mut [String], find: &str) -> NaiveDate {
     }
 }
 
+/// Gets an end date argument, where `open` means that the period is open-ended (it has no end date).
+/// # Arguments
+/// * `args` - Arguments of the command.
+/// * `find` - Option preceding the end date.
+pub fn get_end_date_arg(args: &mut [String], find: &str) -> Option<NaiveDate> {
+    match get_specific_arg(args, find) {
+        Some(end) if end.trim().eq_ignore_ascii_case("open") => None,
+        Some(end) => Some(parse_date(end.trim())),
+        None => {
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
+
+/// Gets a phase of the given plan from a phase argument (id or name), exiting if there isn't any.
+/// # Arguments
+/// * `phase_arg` - Phase argument.
+/// * `conn` - Database connection.
+/// * `plan_id` - Id of the plan of the phase.
+pub fn get_plan_phase(phase_arg: &str, conn: &mut SqliteConnection, plan_id: i32) -> Phase {
+    let phase_id = phase_arg.trim().parse::<i32>().ok();
+    match Phase::fetch_by_period(conn, plan_id)
+        .into_iter()
+        .find(|p| Some(p.id) == phase_id || p.name.eq_ignore_ascii_case(phase_arg.trim()))
+    {
+        Some(p) => p,
+        None => {
+            eprintln!("There is no phase with that id or name in the period");
+            process::exit(1);
+        }
+    }
+}
+
+/// Parses the end date of a new plan, where `open` means that the plan is open-ended.
+/// # Arguments
+/// * `date` - End date argument.
+fn parse_end_date(date: &str) -> Result<Option<NaiveDate>, ParseError> {
+    match date.trim().eq_ignore_ascii_case("open") {
+        true => Ok(None),
+        false => NaiveDate::parse_from_str(date, FORMAT).map(Some),
+    }
+}
+
 pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
     if args.is_empty() {
         display_bad_usage();
@@ -70,16 +118,89 @@ pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
             "list" => {
                 list::list(conn);
             }
+            "break" => {
+                breaks::interpret(args, conn);
+            }
+            "phase" => {
+                phases::interpret(args, conn);
+            }
+            "clone" => {
+                if let Some(o) = detect_unknown_arg(
+                    args,
+                    &vec![
+                        "--start",
+                        "--end",
+                        "--description",
+                        "--goals",
+                        "--credits",
+                        "--overlap",
+                    ],
+                    "--",
+                ) {
+                    eprintln!("Unknown argument: {o}");
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                let source = match args.first().map(|i| i.parse::<i32>()) {
+                    Some(Ok(i)) => match Period::from_id(conn, i) {
+                        Some(p) => p,
+                        None => {
+                            eprintln!("Period with id {i} not found");
+                            process::exit(1);
+                        }
+                    },
+                    _ => {
+                        display_bad_usage();
+                        process::exit(1);
+                    }
+                };
+                let start = get_date_arg(args, "--start");
+                let end = get_end_date_arg(args, "--end");
+                let descr = match args.contains(&"--description".to_string()) {
+                    true => match get_specific_arg(args, "--description") {
+                        Some(d) => d,
+                        None => {
+                            display_bad_usage();
+                            process::exit(1);
+                        }
+                    },
+                    false => format!("{} (copy)", source.description),
+                };
+                clone::clone_plan(
+                    conn,
+                    &source,
+                    start,
+                    end,
+                    descr,
+                    args.contains(&"--goals".to_string()),
+                    args.contains(&"--credits".to_string()),
+                    args.contains(&"--overlap".to_string()),
+                );
+            }
+            "apply" => match args.first() {
+                Some(path) => apply::apply(conn, path),
+                None => {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+            },
             "start" => {
+                let overlap = match args.iter().position(|a| a == "--overlap") {
+                    Some(i) => {
+                        args.remove(i);
+                        true
+                    }
+                    None => false,
+                };
                 if args.len() < 2 {
                     display_bad_usage();
                     process::exit(1);
                 } else {
-                    let (_start, _end, _description): (NaiveDate, NaiveDate, String) =
+                    let (_start, _end, _description): (NaiveDate, Option<NaiveDate>, String) =
                         match args.len() {
                             2 => (
-                                Local::now().naive_local().date(),
-                                match NaiveDate::parse_from_str(&args[0], FORMAT) {
+                                today(),
+                                match parse_end_date(&args[0]) {
                                     Ok(date) => date,
                                     Err(e) => {
                                         eprintln!(
@@ -105,7 +226,7 @@ pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
                                         process::exit(1);
                                     }
                                 },
-                                match NaiveDate::parse_from_str(&args[1], FORMAT) {
+                                match parse_end_date(&args[1]) {
                                     Ok(date) => date,
                                     Err(e) => {
                                         eprintln!(
@@ -123,7 +244,7 @@ pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
                                 process::exit(1);
                             }
                         };
-                    start::start_plan(conn, _start, _end, _description);
+                    start::start_plan(conn, _start, _end, _description, overlap);
                 }
             }
             "remove" => {
@@ -182,8 +303,8 @@ pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
                     true => get_date_arg(args, "--start"),
                     false => plan.initial_date,
                 };
-                let new_end_date: NaiveDate = match args.contains(&"--end".to_string()) {
-                    true => get_date_arg(args, "--end"),
+                let new_end_date: Option<NaiveDate> = match args.contains(&"--end".to_string()) {
+                    true => get_end_date_arg(args, "--end"),
                     false => plan.final_date,
                 };
                 let descr: String = match args.contains(&"--description".to_string()) {
@@ -196,7 +317,25 @@ pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
                     },
                     false => plan.description.clone(),
                 };
-                modify::modify(conn, plan_id, new_start_date, new_end_date, descr);
+                let scale: GradingScale = match get_specific_arg(args, "--scale") {
+                    Some(s) => match s.parse::<GradingScale>() {
+                        Ok(scale) => scale,
+                        Err(e) => {
+                            eprintln!("{e}");
+                            process::exit(1);
+                        }
+                    },
+                    None => plan.scale(),
+                };
+                modify::modify(
+                    conn,
+                    plan_id,
+                    new_start_date,
+                    new_end_date,
+                    descr,
+                    scale,
+                    args.contains(&"--overlap".to_string()),
+                );
             }
             k => {
                 debug_println!("No valid argument. Provided: {k}");
@@ -1,8 +1,10 @@
 //! Module for modifying existing periods.
 
-use crate::models::Period;
+use crate::models::{GradingScale, Period};
 use crate::schema::periods::dsl::periods;
-use crate::schema::periods::{description, final_date, id, initial_date};
+use crate::schema::periods::{description, final_date, grading_scale, id, initial_date};
+use crate::schema::subjects::dsl::subjects;
+use crate::schema::subjects::{final_score, id as subject_id};
 use diesel::internal::derives::multiconnection::chrono::NaiveDate;
 use diesel::ExpressionMethods;
 use diesel::{update, SqliteConnection};
@@ -13,22 +15,51 @@ pub fn modify(
     conn: &mut SqliteConnection,
     plan_id: i32,
     new_start_date: NaiveDate,
-    new_end_date: NaiveDate,
+    new_end_date: Option<NaiveDate>,
     new_description: String,
+    new_scale: GradingScale,
+    allow_overlap: bool,
 ) {
+    if new_end_date.is_some_and(|end| new_start_date > end) {
+        eprintln!("Invalid arguments: Start date can't be after end date");
+        process::exit(1);
+    }
     for p in Period::fetch_all_plans(conn) {
-        if p.id != plan_id && p.overlaps((new_start_date, new_end_date)) {
-            eprintln!("The modified period cannot overlap another period.");
+        if !allow_overlap
+            && p.id != plan_id
+            && p.overlaps((new_start_date, new_end_date.unwrap_or(NaiveDate::MAX)))
+        {
+            eprintln!(
+                "The modified period cannot overlap another period (use --overlap to allow it)."
+            );
             eprintln!("Overlapped period: {}", p);
             process::exit(1);
         }
     }
 
+    // Marks are kept in the grading scale of their period, so they are converted if the scale changes.
+    if let Some(plan) = Period::from_id(conn, plan_id) {
+        if plan.scale() != new_scale {
+            for s in plan.fetch_subjects(conn) {
+                if let Some(score) = s.final_score {
+                    if let Err(e) = update(subjects.filter(subject_id.eq(s.id)))
+                        .set(final_score.eq(plan.scale().convert(score, new_scale)))
+                        .execute(conn)
+                    {
+                        eprintln!("Failed to convert the mark of {}: {e}", s.short_name);
+                        process::exit(1);
+                    }
+                }
+            }
+        }
+    }
+
     match update(periods.filter(id.eq(plan_id)))
         .set((
             initial_date.eq(new_start_date),
             final_date.eq(new_end_date),
             description.eq(new_description),
+            grading_scale.eq(new_scale.to_string()),
         ))
         .execute(conn)
     {
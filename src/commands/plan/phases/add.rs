@@ -0,0 +1,60 @@
+//! Handles phase adding.
+use crate::models::{Period, Phase};
+use crate::schema::phases::dsl::phases;
+use crate::schema::phases::{end_date, name, period_id, start_date};
+use diesel::dsl::insert_into;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::ExpressionMethods;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Adds a new phase to a period. Phases of the same period can't overlap nor share a name.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the phase.
+/// * `new_name` - Name of the phase (e.g.: revision).
+/// * `start` - First day of the phase.
+/// * `end` - Last day of the phase.
+pub fn add(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    new_name: String,
+    start: NaiveDate,
+    end: NaiveDate,
+) {
+    if start > end {
+        eprintln!("The start of the phase must be before its end.");
+        process::exit(1);
+    }
+    if start < period.initial_date || end > period.end_date() {
+        eprintln!("The phase must be inside the period.");
+        process::exit(1);
+    }
+    for p in Phase::fetch_by_period(conn, period.id) {
+        if p.name.eq_ignore_ascii_case(&new_name) {
+            eprintln!("There is already a phase named '{}' in the period.", p.name);
+            process::exit(1);
+        }
+        if p.start_date <= end && p.end_date >= start {
+            eprintln!("The phase overlaps another one:\n\t{p}");
+            process::exit(1);
+        }
+    }
+    match insert_into(phases)
+        .values((
+            period_id.eq(period.id),
+            name.eq(new_name),
+            start_date.eq(start),
+            end_date.eq(end),
+        ))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!("Phase added succesfully");
+        }
+        Err(e) => {
+            eprintln!("Could not insert phase into database: {}", e);
+            process::exit(1);
+        }
+    }
+}
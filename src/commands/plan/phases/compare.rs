@@ -0,0 +1,75 @@
+//! Handles the comparison of a phase between plans (e.g.: the first revision week of every year).
+use crate::models::Phase;
+use crate::{format_hours_and_minutes, today, FORMAT};
+use colored::Colorize;
+use diesel::SqliteConnection;
+
+/// Compares the time studied during the phases with the same name of every plan, or during one of their weeks.
+/// Each phase is compared with the previous one.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `phase_name` - Name of the phases to compare.
+/// * `week` - Week of the phases to compare (starting at 1). If None, the whole phases are compared.
+pub fn compare(conn: &mut SqliteConnection, phase_name: &str, week: Option<i64>) {
+    let matches = Phase::fetch_by_name(conn, phase_name);
+    if matches.is_empty() {
+        println!("There is no phase named '{phase_name}' in any plan.");
+        return;
+    }
+    match week {
+        Some(w) => println!("Week {w} of the '{phase_name}' phase:"),
+        None => println!("'{phase_name}' phase:"),
+    }
+    let today = today();
+    let mut previous: Option<f64> = None;
+    for (phase, period) in matches {
+        let interval = match week {
+            Some(w) => match phase.week_interval(w) {
+                Some(interval) => interval,
+                None => {
+                    println!(
+                        "\t * {}: the phase has only {} weeks",
+                        period.description,
+                        phase.weeks()
+                    );
+                    continue;
+                }
+            },
+            None => (phase.start_date, phase.end_date),
+        };
+        if interval.0 > today {
+            println!(
+                "\t * {}: starts on {}",
+                period.description,
+                interval.0.format(FORMAT)
+            );
+            continue;
+        }
+        let so_far = match interval.1 > today {
+            true => " so far",
+            false => "",
+        };
+        let interval = (interval.0, interval.1.min(today));
+        let total = period.total_dedicated_time_interval(conn, interval);
+        let days = (interval.1 - interval.0).num_days() + 1;
+        let daily = total as f64 / days as f64;
+        let difference = match previous {
+            Some(p) if p > 0.0 => match (daily - p) / p * 100.0 {
+                d if d >= 0.0 => format!(", {:+.1}% daily vs. previous", d).green(),
+                d => format!(", {:+.1}% daily vs. previous", d).red(),
+            },
+            _ => "".normal(),
+        };
+        println!(
+            "\t * {}: {} - {}: {}{} ({}/day){}",
+            period.description,
+            interval.0.format(FORMAT),
+            interval.1.format(FORMAT),
+            format_hours_and_minutes(total),
+            so_far,
+            format_hours_and_minutes(daily.round() as i32),
+            difference
+        );
+        previous = Some(daily);
+    }
+}
@@ -0,0 +1,19 @@
+//! Handles phase listing.
+use crate::models::{Period, Phase};
+use diesel::SqliteConnection;
+
+/// Lists the phases of a period.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the phases.
+pub fn list(conn: &mut SqliteConnection, period: &Period) {
+    let period_phases = Phase::fetch_by_period(conn, period.id);
+    if period_phases.is_empty() {
+        println!("No phases from this period");
+        return;
+    }
+    println!("Phases of {} (ID:{}):", period.description, period.id);
+    for p in period_phases {
+        println!("\t{p}");
+    }
+}
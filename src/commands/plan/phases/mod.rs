@@ -0,0 +1,107 @@
+//! Handles commands related to the phases of a plan: named date ranges (teaching, revision, exams...) with
+//! different expected workloads, which can have their own goals and be compared between plans.
+
+mod add;
+mod compare;
+mod list;
+mod remove;
+
+use crate::commands::plan::usage::display_bad_usage;
+use crate::commands::plan::{get_plan_arg, get_plan_phase};
+use crate::debug_println;
+use crate::interpreter::{detect_unknown_arg, get_specific_arg, parse_date};
+use crate::models::Period;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Gets the period of a plan id, exiting if it doesn't exist.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `plan_id` - Id of the plan.
+fn get_period(conn: &mut SqliteConnection, plan_id: i32) -> Period {
+    match Period::from_id(conn, plan_id) {
+        Some(period) => period,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    }
+}
+
+/// Interprets phase subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    match option.trim() {
+        "add" => {
+            let plan_id = get_plan_arg(args, conn);
+            if args.len() < 3 {
+                display_bad_usage();
+                process::exit(1);
+            }
+            let period = get_period(conn, plan_id);
+            let name = args[0].trim().to_string();
+            if name.parse::<i32>().is_ok() {
+                eprintln!("The name of a phase can't be a number");
+                process::exit(1);
+            }
+            let start = parse_date(args[1].trim());
+            let end = parse_date(args[2].trim());
+            add::add(conn, &period, name, start, end);
+        }
+        "list" => {
+            let plan_id = get_plan_arg(args, conn);
+            let period = get_period(conn, plan_id);
+            list::list(conn, &period);
+        }
+        "remove" => {
+            let plan_id = get_plan_arg(args, conn);
+            match args.first() {
+                Some(p) => {
+                    let phase = get_plan_phase(p, conn, plan_id);
+                    remove::remove(conn, &phase);
+                }
+                None => {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+            }
+        }
+        "compare" => {
+            if let Some(o) = detect_unknown_arg(args, &vec!["--week"], "--") {
+                eprintln!("Unknown argument: {o}");
+                display_bad_usage();
+                process::exit(1);
+            }
+            let week = match args.contains(&"--week".to_string()) {
+                true => match get_specific_arg(args, "--week").map(|w| w.parse::<i64>()) {
+                    Some(Ok(w)) if w > 0 => Some(w),
+                    _ => {
+                        eprintln!("The week must be a positive integer");
+                        process::exit(1);
+                    }
+                },
+                false => None,
+            };
+            match args.first() {
+                Some(name) if !name.starts_with("--") => compare::compare(conn, name, week),
+                _ => {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+            }
+        }
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Handles phase removal.
+use crate::models::Phase;
+use crate::schema::goals::dsl::goals;
+use crate::schema::goals::phase_id;
+use crate::schema::phases::dsl::phases;
+use crate::schema::phases::id;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, Connection, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Removes an existing phase, along with its goals.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `phase` - Phase to remove.
+pub fn remove(conn: &mut SqliteConnection, phase: &Phase) {
+    let result = conn.transaction(|conn| {
+        delete(goals.filter(phase_id.eq(phase.id))).execute(conn)?;
+        delete(phases.filter(id.eq(phase.id))).execute(conn)
+    });
+    match result {
+        Ok(_) => {
+            println!("Phase removed succesfully");
+        }
+        Err(e) => {
+            eprintln!("Error deleting phase: {e}");
+            process::exit(1);
+        }
+    }
+}
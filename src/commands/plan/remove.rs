@@ -1,12 +1,26 @@
+use crate::commands::subject::delete_subjects;
 use crate::schema::periods::dsl::periods;
 use crate::schema::periods::id;
+use crate::schema::{breaks, goals, phases, subjects};
 use diesel::ExpressionMethods;
 use diesel::QueryDsl;
-use diesel::{delete, RunQueryDsl, SqliteConnection};
+use diesel::{delete, Connection, QueryResult, RunQueryDsl, SqliteConnection};
 use std::process;
 
 pub fn remove_plan(conn: &mut SqliteConnection, plan: i32) {
-    match delete(periods.filter(id.eq(plan))).execute(conn) {
+    let result: QueryResult<()> = conn.transaction(|conn| {
+        let plan_subjects = subjects::table
+            .filter(subjects::period_id.eq(plan))
+            .select(subjects::id)
+            .load::<i32>(conn)?;
+        delete_subjects(conn, &plan_subjects)?;
+        delete(goals::table.filter(goals::period_id.eq(plan))).execute(conn)?;
+        delete(phases::table.filter(phases::period_id.eq(plan))).execute(conn)?;
+        delete(breaks::table.filter(breaks::period_id.eq(plan))).execute(conn)?;
+        delete(periods.filter(id.eq(plan))).execute(conn)?;
+        Ok(())
+    });
+    match result {
         Ok(_) => {
             println!("Plan deleted successfully");
             process::exit(0);
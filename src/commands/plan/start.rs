@@ -9,18 +9,22 @@ use std::process;
 pub fn start_plan(
     conn: &mut SqliteConnection,
     new_start: NaiveDate,
-    new_end: NaiveDate,
+    new_end: Option<NaiveDate>,
     new_description: String,
+    allow_overlap: bool,
 ) {
-    if new_start.gt(&new_end) {
+    if new_end.is_some_and(|end| new_start.gt(&end)) {
         eprintln!("Invalid arguments: Start date can't be after end date");
         process::exit(1);
     }
 
-    for p in Period::fetch_all_plans(conn) {
-        if p.overlaps((new_start, new_end)) {
-            eprintln!("Invalid state: Current study period overlaps the provided period.");
-            process::exit(1);
+    if !allow_overlap {
+        for p in Period::fetch_all_plans(conn) {
+            if p.overlaps((new_start, new_end.unwrap_or(NaiveDate::MAX))) {
+                eprintln!("Invalid state: Current study period overlaps the provided period.");
+                eprintln!("Use --overlap to create it anyway.");
+                process::exit(1);
+            }
         }
     }
 
@@ -3,10 +3,20 @@ use crate::FORMAT;
 pub fn display_bad_usage() {
     println!(
         "Bad usage: {} plan ...:\n
-        - start [start] (end) (description) : Starts a new study plan. It starts today if no start date is provided.
+        - start [start] (end/open) (description) [--overlap] : Starts a new study plan. It starts today if no start date is provided. Use open as end date for plans without an end (language courses, certifications...), and --overlap to allow the plan to overlap other plans.
         - list : Lists all the study periods.
-        - modify [--plan (plan id)] [--start (new start date)] [--end (new end date)] [--description (new description)] : Modifies the current plan (or one determined by an id).
+        - modify [--plan (plan id)] [--start (new start date)] [--end (new end date/open)] [--description (new description)] [--scale (0-10/0-20/A-F/4.0)] [--overlap] : Modifies the current plan (or one determined by an id). The scale is the grading scale of the final scores.
+        - clone (plan id) --start (start date) --end (end date/open) [--description (description)] [--goals] [--credits] [--overlap] : Creates a new plan with the subjects of another one (without entries). Use --goals and --credits to copy them too.
+        - apply (plan.toml) : Creates or updates a plan, with its subjects, exams and goals, as described in a TOML file. Applying the same file again doesn't change anything.
         - remove [plan id] [--confirm] : Removes the actual study plan (or one determined by id). Use the --confirm option to do so without any warning.
+        - break add (start) (end) (label) [--plan (plan id)] : Adds a break (holidays, exam-only weeks...) to the current plan (or one determined by an id). Breaks are left out of the weekly averages.
+        - break list [--plan (plan id)] : Lists the breaks of the plan.
+        - break remove (break id) : Removes a break.
+        - break import (file.ics) [--plan (plan id)] : Imports every event of an iCalendar file as a break of the plan.
+        - phase add (name) (start) (end) [--plan (plan id)] : Adds a phase (teaching, revision, exams...) to the current plan (or one determined by an id). Phases can't overlap.
+        - phase list [--plan (plan id)] : Lists the phases of the plan.
+        - phase remove (phase id/name) [--plan (plan id)] : Removes a phase and its goals.
+        - phase compare (name) [--week (n)] : Compares the time studied during the phases with that name of every plan (or during their n-th week).
         The date format is: {FORMAT}\n\
     ", crate::env::args().collect::<Vec<String>>().first().unwrap());
 }
@@ -0,0 +1,93 @@
+//! # Pomodoro mode.
+//! The pomodoro command runs cycles of focus (pomodoros) and break time in the terminal, logging the time of the
+//! completed pomodoros and recording the interrupted ones, so `pomodoro stats` can tell how often focus is broken.
+
+mod run;
+mod stats;
+mod usage;
+
+use crate::commands::pomodoro::usage::display_bad_usage;
+use crate::commands::subject::get_active_subject;
+use crate::interpreter::{detect_unknown_arg, parse_duration, take_specific_arg};
+use crate::models::{ActivityType, DEFAULT_ACTIVITY};
+use crate::today;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Default minutes of a pomodoro.
+const DEFAULT_WORK: i32 = 25;
+/// Default minutes of a break.
+const DEFAULT_BREAK: i32 = 5;
+/// Default number of pomodoros in a session.
+const DEFAULT_CYCLES: i32 = 4;
+
+/// Parses a duration argument, exiting if it isn't valid.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `arg` - Name of the argument.
+/// * `default` - Minutes if the argument isn't given.
+fn take_duration(args: &mut Vec<String>, arg: &str, default: i32) -> i32 {
+    match take_specific_arg(args, arg).map(|d| parse_duration(&d)) {
+        Some(Some(minutes)) => minutes,
+        Some(None) => {
+            eprintln!("{arg} must be a positive or zero duration (e.g.: 25 or 1h30m)");
+            process::exit(1);
+        }
+        None => default,
+    }
+}
+
+/// Interprets pomodoro subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    if args[0] == "stats" {
+        args.remove(0);
+        stats::stats(conn, args);
+        return;
+    }
+    if let Some(o) =
+        detect_unknown_arg(args, &vec!["--work", "--break", "--cycles", "--type"], "--")
+    {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let work = take_duration(args, "--work", DEFAULT_WORK);
+    if work == 0 {
+        eprintln!("--work must be at least a minute");
+        process::exit(1);
+    }
+    let rest = take_duration(args, "--break", DEFAULT_BREAK);
+    let cycles = match take_specific_arg(args, "--cycles").map(|c| c.parse::<i32>()) {
+        Some(Ok(c)) if c > 0 => c,
+        Some(_) => {
+            eprintln!("--cycles must be a positive number");
+            process::exit(1);
+        }
+        None => DEFAULT_CYCLES,
+    };
+    let activity_name = match take_specific_arg(args, "--type") {
+        Some(t) => match ActivityType::from_name(conn, &t) {
+            Some(a) => a.name,
+            None => {
+                eprintln!("Unknown activity type '{t}'. Use `activity list` to see them, or `activity add` to add it.");
+                process::exit(1);
+            }
+        },
+        None => String::from(DEFAULT_ACTIVITY),
+    };
+    if args.len() != 1 {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let today = today();
+    let subject = get_active_subject(&args[0], conn, today);
+    subject.exit_if_archived(today);
+    run::run(conn, &subject, &activity_name, (work, rest, cycles));
+}
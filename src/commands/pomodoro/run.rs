@@ -0,0 +1,123 @@
+//! Handles running a pomodoro session in the terminal.
+use crate::commands::entry::add_time;
+use crate::models::{Pomodoro, Session, Subject};
+use crate::{format_hours_and_minutes, study_day};
+use diesel::internal::derives::multiconnection::chrono::Local;
+use diesel::SqliteConnection;
+use std::io::Write;
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+use std::{io, thread};
+
+/// How often the countdown is refreshed.
+const REFRESH: Duration = Duration::from_millis(200);
+/// Columns taken by the tab before the countdown.
+const TAB_WIDTH: usize = 8;
+
+/// Prints a countdown in a single line until the time is up or Enter is pressed. Returns how long it lasted if it
+/// was interrupted.
+/// # Arguments
+/// * `label` - What is being counted down (e.g.: "Focus 1/4").
+/// * `minutes` - Minutes to count down.
+/// * `interruptions` - Receives a message every time Enter is pressed.
+fn countdown(label: &str, minutes: i32, interruptions: &Receiver<()>) -> Option<Duration> {
+    let total = Duration::from_secs(minutes as u64 * 60);
+    // Enter presses from before the countdown started don't interrupt it.
+    while interruptions.try_recv().is_ok() {}
+    let start = Instant::now();
+    let mut line = String::new();
+    loop {
+        let elapsed = start.elapsed();
+        if elapsed >= total {
+            // The countdown is cleared, so the line can be reused.
+            print!("\r{}\r", " ".repeat(line.chars().count() + TAB_WIDTH));
+            return None;
+        }
+        if interruptions.try_recv().is_ok() {
+            return Some(elapsed);
+        }
+        let left = (total - elapsed).as_secs();
+        line = format!(
+            "{label} - {:02}:{:02} left (press Enter to interrupt) ",
+            left / 60,
+            left % 60
+        );
+        print!("\r\t{line}");
+        let _ = io::stdout().flush();
+        thread::sleep(REFRESH);
+    }
+}
+
+/// Runs a pomodoro session: some cycles of focus and break time. The time of every completed pomodoro is logged to
+/// the subject as soon as it ends, and interrupting a pomodoro ends the session.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject studied.
+/// * `activity_name` - Activity type of the logged time.
+/// * `session` - (focus minutes, break minutes, number of pomodoros) of the session.
+pub fn run(
+    conn: &mut SqliteConnection,
+    subject: &Subject,
+    activity_name: &str,
+    session: (i32, i32, i32),
+) {
+    let (work, rest, cycles) = session;
+    let (sender, interruptions) = mpsc::channel();
+    thread::spawn(move || {
+        let mut line = String::new();
+        // Without a terminal (e.g.: stdin closed), the session can't be interrupted.
+        while let Ok(n) = io::stdin().read_line(&mut line) {
+            if n == 0 || sender.send(()).is_err() {
+                break;
+            }
+            line.clear();
+        }
+    });
+    println!(
+        "Pomodoro session of {}: {cycles} x {} of focus, with {} breaks",
+        subject.name,
+        format_hours_and_minutes(work),
+        format_hours_and_minutes(rest)
+    );
+    let mut logged = 0;
+    for cycle in 1..=cycles {
+        let start = Local::now().naive_local();
+        if let Some(elapsed) = countdown(&format!("Focus {cycle}/{cycles}"), work, &interruptions) {
+            let focused = (elapsed.as_secs() / 60) as i32;
+            Pomodoro::record(conn, subject.id, start, work, focused);
+            println!(
+                "\tPomodoro interrupted after {}. It wasn't logged: use `add` if you want to log it anyway.",
+                format_hours_and_minutes(focused)
+            );
+            break;
+        }
+        Pomodoro::record(conn, subject.id, start, work, work);
+        Session::record(conn, subject.id, start, work);
+        // The time is logged to the study day the pomodoro started on.
+        add_time(conn, subject, study_day(start), activity_name, work);
+        logged += work;
+        println!(
+            "\x07\tPomodoro {cycle}/{cycles} completed: {} logged to {}",
+            format_hours_and_minutes(work),
+            subject.short_name
+        );
+        if cycle < cycles && rest > 0 {
+            if countdown(
+                &format!("Break {cycle}/{}", cycles - 1),
+                rest,
+                &interruptions,
+            )
+            .is_some()
+            {
+                println!("\tBreak interrupted.");
+                break;
+            }
+            print!("\x07");
+        }
+    }
+    println!(
+        "Session finished: {} of focus logged to {}",
+        format_hours_and_minutes(logged),
+        subject.name
+    );
+}
@@ -0,0 +1,74 @@
+//! Handles the statistics of the pomodoros of a plan.
+use crate::commands::plan::get_plan_arg;
+use crate::commands::pomodoro::usage::display_bad_usage;
+use crate::format_hours_and_minutes;
+use crate::interpreter::detect_unknown_arg;
+use crate::models::{Period, Pomodoro};
+use diesel::internal::derives::multiconnection::chrono::Timelike;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Describes how many of some pomodoros were completed.
+/// # Arguments
+/// * `list` - Pomodoros to describe.
+fn completion(list: &[&Pomodoro]) -> String {
+    let completed = list.iter().filter(|p| p.completed).count();
+    format!(
+        "{completed} of {} completed ({:.1}%)",
+        list.len(),
+        completed as f64 / list.len() as f64 * 100.0
+    )
+}
+
+/// Shows the completion rate of the pomodoros of a plan by subject and by the hour of the day they started, based on
+/// program args.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `args` - Remaining program arguments.
+pub fn stats(conn: &mut SqliteConnection, args: &mut Vec<String>) {
+    let plan_id = get_plan_arg(args, conn);
+    if let Some(o) = detect_unknown_arg(args, &vec![], "--") {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let period = match Period::from_id(conn, plan_id) {
+        Some(period) => period,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    };
+    let subject_list = period.fetch_subjects(conn);
+    let ids: Vec<i32> = subject_list.iter().map(|s| s.id).collect();
+    let all = Pomodoro::fetch_by_subjects(conn, &ids);
+    println!("Plan: {} (ID:{})", period.description, period.id);
+    if all.is_empty() {
+        println!("No pomodoros yet. Start one with `pomodoro (subject)`.");
+        return;
+    }
+    let focused: i32 = all
+        .iter()
+        .filter(|p| p.completed)
+        .map(|p| p.focused_minutes)
+        .sum();
+    println!(
+        "\t{}, {} of focus logged.",
+        completion(&all.iter().collect::<Vec<&Pomodoro>>()),
+        format_hours_and_minutes(focused)
+    );
+    println!("\tBy subject:");
+    for s in &subject_list {
+        let of_subject: Vec<&Pomodoro> = all.iter().filter(|p| p.subject_id == s.id).collect();
+        if !of_subject.is_empty() {
+            println!("\t * {}: {}", s.paint(&s.name), completion(&of_subject));
+        }
+    }
+    println!("\tBy hour of the day:");
+    for hour in 0..24 {
+        let of_hour: Vec<&Pomodoro> = all.iter().filter(|p| p.started_at.hour() == hour).collect();
+        if !of_hour.is_empty() {
+            println!("\t * {hour:02}:00: {}", completion(&of_hour));
+        }
+    }
+}
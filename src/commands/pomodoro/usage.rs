@@ -0,0 +1,14 @@
+//! Pomodoro module usage
+
+/// Displays the bad usage message of the pomodoro module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} pomodoro ...:\n
+        - (subject id or short name) [--work (time)] [--break (time)] [--cycles (n)] [--type (activity type)] : Runs n cycles of focus and\n\
+          break time (25 and 5 minutes, 4 times, by default) with a countdown. The time of every completed pomodoro is logged to the\n\
+          subject. Press Enter to interrupt the current pomodoro: it is recorded as interrupted, and the session ends.\n\
+        - stats [--plan (plan id)] : Shows how many pomodoros were completed, by subject and by the hour of the day they started.\n\
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
@@ -0,0 +1,31 @@
+//! Handles program creation.
+use crate::schema::programs::dsl::programs;
+use crate::schema::programs::{name, required_credits};
+use diesel::ExpressionMethods;
+use diesel::{insert_into, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Adds a new program.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `new_name` - Name of the program.
+/// * `credits` - Credits required to graduate.
+pub fn add(conn: &mut SqliteConnection, new_name: String, credits: f32) {
+    if credits <= 0.0 {
+        eprintln!("The required credits must be positive.");
+        process::exit(1);
+    }
+    match insert_into(programs)
+        .values((name.eq(new_name), required_credits.eq(credits)))
+        .returning(crate::schema::programs::id)
+        .get_result::<i32>(conn)
+    {
+        Ok(new_id) => {
+            println!("Program added succesfully (ID:{new_id})");
+        }
+        Err(e) => {
+            eprintln!("Failed to insert program: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Handles the assignment of plans to programs.
+use crate::models::{Period, Program};
+use crate::schema::periods::dsl::periods;
+use crate::schema::periods::{academic_year, id, program_id};
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{update, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Adds a plan to an academic year of a program. A plan belongs to one program at most, so it is moved if it was in
+/// another one.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `program` - Program to add the plan to.
+/// * `period` - Plan to add.
+/// * `year` - Academic year of the program (starting at 1).
+pub fn assign(conn: &mut SqliteConnection, program: &Program, period: &Period, year: i32) {
+    if year < 1 {
+        eprintln!("The academic year must be 1 or greater.");
+        process::exit(1);
+    }
+    match update(periods.filter(id.eq(period.id)))
+        .set((program_id.eq(program.id), academic_year.eq(year)))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!(
+                "{} is now in year {year} of {}",
+                period.description, program.name
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to assign the plan: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Removes a plan from its program.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Plan to remove from its program.
+pub fn unassign(conn: &mut SqliteConnection, period: &Period) {
+    if period.program_id.is_none() {
+        eprintln!("{} isn't in any program.", period.description);
+        process::exit(1);
+    }
+    match update(periods.filter(id.eq(period.id)))
+        .set((program_id.eq(None::<i32>), academic_year.eq(None::<i32>)))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!("{} removed from its program", period.description);
+        }
+        Err(e) => {
+            eprintln!("Failed to unassign the plan: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Handles program listing.
+use crate::models::Program;
+use diesel::SqliteConnection;
+
+/// Lists all programs with their periods, grouped by academic year.
+/// # Arguments
+/// * `conn` - Database connection.
+pub fn list(conn: &mut SqliteConnection) {
+    let all = Program::fetch_all(conn);
+    if all.is_empty() {
+        println!("No programs created yet.");
+        return;
+    }
+    println!("Programs:");
+    for program in all {
+        println!("{program}");
+        let mut year: Option<i32> = None;
+        for p in program.fetch_periods(conn) {
+            if p.academic_year != year {
+                year = p.academic_year;
+                match year {
+                    Some(y) => println!("\tYear {y}:"),
+                    None => println!("\tWithout year:"),
+                }
+            }
+            println!("\t * {p}");
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Handles commands related to programs (degrees), which group plans into academic years with the credits required
+//! to graduate.
+
+mod add;
+mod assign;
+mod list;
+mod remove;
+mod status;
+mod usage;
+
+use crate::commands::program::usage::display_bad_usage;
+use crate::interpreter::{detect_unknown_arg, get_specific_arg, request_confirmation};
+use crate::models::{GradingScale, Period, Program};
+use crate::{debug_println, today};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Gets a program from an id argument, exiting if there isn't any.
+/// # Arguments
+/// * `program_arg` - Program id argument.
+/// * `conn` - Database connection.
+fn get_program(program_arg: &str, conn: &mut SqliteConnection) -> Program {
+    match program_arg.trim().parse::<i32>() {
+        Ok(i) => match Program::from_id(conn, i) {
+            Some(p) => p,
+            None => {
+                eprintln!("There is no program with id {i}");
+                process::exit(1);
+            }
+        },
+        Err(_) => {
+            eprintln!("Failed to parse ID. Did you give a number?");
+            process::exit(1);
+        }
+    }
+}
+
+/// Gets a plan from an id argument, exiting if there isn't any.
+/// # Arguments
+/// * `plan_arg` - Plan id argument.
+/// * `conn` - Database connection.
+fn get_period(plan_arg: &str, conn: &mut SqliteConnection) -> Period {
+    match plan_arg
+        .trim()
+        .parse::<i32>()
+        .ok()
+        .and_then(|i| Period::from_id(conn, i))
+    {
+        Some(p) => p,
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    }
+}
+
+/// Interprets program subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    match option.trim() {
+        "add" => {
+            let credits = match get_specific_arg(args, "--credits").map(|c| c.parse::<f32>()) {
+                Some(Ok(c)) if c.is_finite() && c > 0.0 => c,
+                Some(_) => {
+                    eprintln!("The required credits must be a positive number");
+                    process::exit(1);
+                }
+                None => {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+            };
+            let index = args.iter().position(|a| a == "--credits").unwrap();
+            args.drain(index..index + 2);
+            if args.is_empty() {
+                display_bad_usage();
+                process::exit(1);
+            }
+            add::add(conn, args.join(" "), credits);
+        }
+        "list" => {
+            list::list(conn);
+        }
+        "assign" => {
+            if args.len() < 2 {
+                display_bad_usage();
+                process::exit(1);
+            }
+            let year = match get_specific_arg(args, "--year").map(|y| y.parse::<i32>()) {
+                Some(Ok(y)) => y,
+                Some(Err(_)) => {
+                    eprintln!("The academic year must be a number");
+                    process::exit(1);
+                }
+                None => {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+            };
+            let program = get_program(&args[0], conn);
+            let period = get_period(&args[1], conn);
+            assign::assign(conn, &program, &period, year);
+        }
+        "unassign" => match args.first() {
+            Some(plan_arg) => {
+                let period = get_period(plan_arg, conn);
+                assign::unassign(conn, &period);
+            }
+            None => {
+                display_bad_usage();
+                process::exit(1);
+            }
+        },
+        "status" => {
+            if let Some(o) = detect_unknown_arg(args, &vec!["--scale"], "--") {
+                eprintln!("Unknown argument: {o}");
+                display_bad_usage();
+                process::exit(1);
+            }
+            let scale = match get_specific_arg(args, "--scale") {
+                Some(s) => match s.parse::<GradingScale>() {
+                    Ok(scale) => Some(scale),
+                    Err(e) => {
+                        eprintln!("{e}");
+                        process::exit(1);
+                    }
+                },
+                None => None,
+            };
+            let program = match args.first().filter(|a| !a.starts_with("--")) {
+                Some(program_arg) => get_program(program_arg, conn),
+                // Without an id, the program of a current plan is used, or the only program if there is one.
+                None => {
+                    let today = today();
+                    let current = Period::fetch_active(conn, &today)
+                        .iter()
+                        .rev()
+                        .find_map(|p| p.program_id);
+                    let all = Program::fetch_all(conn);
+                    match all
+                        .iter()
+                        .find(|p| Some(p.id) == current)
+                        .or(all.first().filter(|_| all.len() == 1))
+                    {
+                        Some(p) => p.clone(),
+                        None => {
+                            eprintln!(
+                                "No program specified, and the current plan isn't in any program."
+                            );
+                            process::exit(1);
+                        }
+                    }
+                }
+            };
+            status::status(conn, &program, scale);
+        }
+        "remove" => {
+            let program = match args.first() {
+                Some(program_arg) => get_program(program_arg, conn),
+                None => {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+            };
+            if !args.contains(&"--confirm".to_string()) {
+                println!("{}", program);
+                request_confirmation("Are you sure you want to remove the program? [Y/N]");
+            }
+            remove::remove(conn, program.id);
+        }
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Handles program removal.
+use crate::schema::periods::dsl::periods;
+use crate::schema::periods::{academic_year, program_id};
+use crate::schema::programs::dsl::programs;
+use crate::schema::programs::id;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, update, Connection, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Removes an existing program. Its plans are kept, without program.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `program` - Program's id.
+pub fn remove(conn: &mut SqliteConnection, program: i32) {
+    let result = conn.transaction(|conn| {
+        update(periods.filter(program_id.eq(program)))
+            .set((program_id.eq(None::<i32>), academic_year.eq(None::<i32>)))
+            .execute(conn)?;
+        delete(programs.filter(id.eq(program))).execute(conn)
+    });
+    match result {
+        Ok(0) => {
+            eprintln!("There is no program with id {program}");
+            process::exit(1);
+        }
+        Ok(_) => {
+            println!("Program removed succesfully");
+        }
+        Err(e) => {
+            eprintln!("Error deleting program: {e}");
+            process::exit(1);
+        }
+    }
+}
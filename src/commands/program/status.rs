@@ -0,0 +1,117 @@
+//! Handles the status of a program: credits, GPA, hours per year and progress toward graduation.
+use crate::commands::gpa::{period_totals, Totals};
+use crate::format_hours_and_minutes;
+use crate::models::{GradingScale, Period, Program};
+use colored::Colorize;
+use diesel::SqliteConnection;
+use std::collections::HashMap;
+
+/// Number of columns of the progress bar.
+const PROGRESS_BAR_WIDTH: usize = 30;
+
+/// Gets the credits of the passed subjects of some periods. The subjects of a course spanning several periods are
+/// only counted once, with the most credits any of them has.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `periods` - Periods of the subjects.
+fn earned_credits(conn: &mut SqliteConnection, periods: &[Period]) -> f32 {
+    let mut earned = 0.0;
+    let mut course_credits: HashMap<i32, f32> = HashMap::new();
+    for period in periods {
+        for s in period.fetch_subjects(conn) {
+            if s.is_passed(period.scale()) != Some(true) {
+                continue;
+            }
+            let credits = s.credits.unwrap_or(0.0);
+            match s.course_id {
+                Some(c) => {
+                    let course = course_credits.entry(c).or_default();
+                    *course = course.max(credits);
+                }
+                None => earned += credits,
+            }
+        }
+    }
+    earned + course_credits.values().sum::<f32>()
+}
+
+/// Prints the status of a program, year by year.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `program` - Program to print.
+/// * `scale` - Grading scale of the averages. If None, the one of the last plan of the program is used.
+pub fn status(conn: &mut SqliteConnection, program: &Program, scale: Option<GradingScale>) {
+    println!("Program: {} (ID:{})", program.name, program.id);
+    let program_periods = program.fetch_periods(conn);
+    if program_periods.is_empty() {
+        println!("No plans in this program yet. Use `program assign` to add them.");
+        return;
+    }
+    let scale = scale.unwrap_or(program_periods.last().unwrap().scale());
+    let mut overall = Totals::default();
+    let mut total_time = 0;
+    let mut years = 0;
+    let mut i = 0;
+    while i < program_periods.len() {
+        let year = program_periods[i].academic_year;
+        match year {
+            Some(y) => println!("Year {y}:"),
+            None => println!("Without year:"),
+        }
+        let mut year_totals = Totals::default();
+        let mut year_time = 0;
+        while i < program_periods.len() && program_periods[i].academic_year == year {
+            let period = &program_periods[i];
+            let time: i32 = period
+                .fetch_subjects(conn)
+                .iter()
+                .map(|s| s.total_dedicated_time(conn))
+                .sum();
+            println!(
+                "\t * {}: {} studied",
+                period,
+                format_hours_and_minutes(time)
+            );
+            year_totals.merge(&period_totals(conn, period, scale));
+            year_time += time;
+            i += 1;
+        }
+        year_totals.print("Average", scale);
+        println!(
+            "\tStudied this year: {}",
+            format_hours_and_minutes(year_time)
+        );
+        overall.merge(&year_totals);
+        total_time += year_time;
+        if year.is_some() {
+            years += 1;
+        }
+    }
+    println!();
+    overall.passed_credits = earned_credits(conn, &program_periods);
+    overall.print("GPA", scale);
+    print!("\tStudied: {}", format_hours_and_minutes(total_time));
+    if years > 0 {
+        print!(
+            " ({} per year)",
+            format_hours_and_minutes(total_time / years)
+        );
+    }
+    println!();
+    let earned = overall.passed_credits;
+    let progress = (earned / program.required_credits).min(1.0);
+    let filled = (progress * PROGRESS_BAR_WIDTH as f32) as usize;
+    let remaining = match program.required_credits - earned {
+        r if r > 0.0 => format!("{r} credits left").normal(),
+        _ => "ready to graduate!".green(),
+    };
+    println!(
+        "\tProgress: [{}{}] {} / {} credits ({:.1}%), {}",
+        "█".repeat(filled),
+        "░".repeat(PROGRESS_BAR_WIDTH - filled),
+        earned,
+        program.required_credits,
+        progress * 100.0,
+        remaining
+    );
+}
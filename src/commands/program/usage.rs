@@ -0,0 +1,16 @@
+//! Program module usage
+
+/// Displays the bad usage message of the program module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} program ...:\n
+        - add (name) --credits (required credits) : Adds a program (degree), like BSc Mathematics.
+        - list : Lists all the programs, with their periods grouped by academic year.
+        - assign (program id) (plan id) --year (n) : Adds a plan to the n-th academic year of a program.
+        - unassign (plan id) : Removes a plan from its program.
+        - status [program id] [--scale (0-10/0-20/A-F/4.0)] : Shows the credits earned from the passed subjects, the GPA, the hours studied every year and the progress toward graduation. Without an id, the program of the current plan is used.
+        - remove (program id) [--confirm] : Removes a program. Its plans are kept.
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
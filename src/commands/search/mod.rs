@@ -0,0 +1,81 @@
+//! # Search of entries.
+//! The search command finds the entries whose note contains a text or that have a tag, in every plan or in a single
+//! one, and lists them like the log command.
+
+mod usage;
+
+use crate::commands::log::print_entries;
+use crate::commands::search::usage::display_bad_usage;
+use crate::format_hours_and_minutes;
+use crate::interpreter::{detect_unknown_arg, take_specific_arg};
+use crate::models::{Entry, Period, Subject};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Searches entries, based on program args.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `args` - Remaining program arguments.
+pub fn search(conn: &mut SqliteConnection, args: &mut Vec<String>) {
+    if let Some(o) = detect_unknown_arg(args, &vec!["--tag", "--plan"], "--") {
+        eprintln!("Unknown argument: {o}");
+        display_bad_usage();
+        process::exit(1);
+    }
+    let subject_list = match take_specific_arg(args, "--plan") {
+        Some(p) => match p
+            .trim()
+            .parse::<i32>()
+            .ok()
+            .and_then(|i| Period::from_id(conn, i))
+        {
+            Some(period) => period.fetch_subjects(conn),
+            None => {
+                eprintln!("There is no period with the provided id");
+                process::exit(1);
+            }
+        },
+        None => Subject::fetch_all(conn),
+    };
+    let mut tag = match take_specific_arg(args, "--tag") {
+        Some(t) => match Entry::normalize_tag(&t) {
+            Some(t) => Some(t),
+            None => {
+                eprintln!("Tags must be single words (e.g.: --tag exercises)");
+                process::exit(1);
+            }
+        },
+        None => None,
+    };
+    let mut text = args.join(" ").trim().to_lowercase();
+    // A single word starting with '#' is a tag.
+    if tag.is_none() && text.starts_with('#') && !text.contains(' ') {
+        tag = Entry::normalize_tag(&text);
+        text.clear();
+    }
+    if tag.is_none() && text.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let ids: Vec<i32> = subject_list.iter().map(|s| s.id).collect();
+    let found: Vec<Entry> = Entry::fetch_by_subjects(conn, &ids, (None, None))
+        .into_iter()
+        .filter(|e| tag.as_ref().is_none_or(|t| e.tag_list().contains(t)))
+        .filter(|e| {
+            text.is_empty()
+                || e.note
+                    .as_ref()
+                    .is_some_and(|n| n.to_lowercase().contains(&text))
+        })
+        .collect();
+    if found.is_empty() {
+        println!("No entries found.");
+        return;
+    }
+    print_entries(&found, &subject_list);
+    println!(
+        "{} entries, {} in total.",
+        found.len(),
+        format_hours_and_minutes(found.iter().map(|e| e.dedicated_time).sum())
+    );
+}
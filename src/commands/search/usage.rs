@@ -0,0 +1,11 @@
+//! Search module usage
+
+/// Displays the bad usage message of the search module.
+pub fn display_bad_usage() {
+    println!(
+        "Bad usage: {} search (text/#tag) [--tag (tag)] [--plan (plan id)]:\n
+        Finds the entries whose note contains the text (ignoring case) or that have the tag, in every plan or in the specified one.\n\
+    ",
+        crate::env::args().collect::<Vec<String>>().first().unwrap()
+    );
+}
@@ -1,12 +1,12 @@
 //! Handles the daily summary of status command.
 use crate::format_hours_and_minutes;
-use crate::models::Subject;
+use colored::ColoredString;
 
 /// Displays the daily summary of the status command.
 /// # Arguments:
 /// * `total_time_studied` - Total study time in the day.
-/// * `times` - List of (Subject, dedicated time) tuples.
-pub(crate) fn daily_summary(total_time_studied: i32, times: &Vec<(Subject, i32)>) {
+/// * `times` - List of (subject or group name, dedicated time) tuples.
+pub(crate) fn daily_summary(total_time_studied: i32, times: &[(ColoredString, i32)]) {
     if total_time_studied > 0 {
         println!(
             "\tYou have studied a total amount of {}:",
@@ -17,7 +17,7 @@ pub(crate) fn daily_summary(total_time_studied: i32, times: &Vec<(Subject, i32)>
                 println!(
                     "\t * {} were dedicated to {}",
                     format_hours_and_minutes(i.1),
-                    i.0.name
+                    i.0
                 );
             }
         }
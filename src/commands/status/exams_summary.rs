@@ -0,0 +1,48 @@
+//! Handles the countdown of upcoming exams of the status command.
+use crate::models::{Exam, Period, Subject};
+use crate::{format_hours_and_minutes, FORMAT};
+use colored::Colorize;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::SqliteConnection;
+
+/// Maximum number of upcoming exams to print.
+const MAX_UPCOMING_EXAMS: usize = 5;
+
+/// Prints a countdown of the upcoming exams, along with the time studied for each subject since its previous
+/// exam (or since the start of the period).
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the exams.
+/// * `upcoming` - Upcoming exams, sorted by date, with their subject.
+/// * `date` - Date of the status.
+pub(crate) fn exams_summary(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    upcoming: &[(Exam, Subject)],
+    date: NaiveDate,
+) {
+    println!("\tUpcoming exams:");
+    for (exam, subject) in upcoming.iter().take(MAX_UPCOMING_EXAMS) {
+        let since = exam
+            .previous_milestone(conn, date)
+            .unwrap_or(period.initial_date);
+        let countdown = match (exam.date - date).num_days() {
+            0 => "today".red().bold(),
+            1 => "tomorrow".red(),
+            d @ 2..=7 => format!("in {d} days").yellow(),
+            d => format!("in {d} days").normal(),
+        };
+        println!(
+            "\t * {} ({}): {} of {}",
+            countdown,
+            exam.date.format(FORMAT),
+            exam.kind,
+            subject.name
+        );
+        println!(
+            "\t   {} studied since {}",
+            format_hours_and_minutes(subject.total_dedicated_time_interval(conn, (since, date))),
+            since.format(FORMAT)
+        );
+    }
+}
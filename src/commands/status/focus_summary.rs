@@ -0,0 +1,16 @@
+//! Handles the focus summary of the status command.
+use crate::format_hours_and_minutes;
+use crate::models::{Entry, MAX_FOCUS};
+
+/// Prints the effective time and the average focus of some entries, if any of them is rated.
+/// # Arguments
+/// * `entries` - Entries of the summarized interval.
+pub(crate) fn focus_summary(entries: &[Entry]) {
+    if let Some(focus) = Entry::average_focus(entries) {
+        let effective: f64 = entries.iter().map(|e| e.effective_minutes()).sum();
+        println!(
+            "\t - Effective time: {} (average focus {focus:.1}/{MAX_FOCUS})",
+            format_hours_and_minutes(effective.round() as i32)
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Handles the goals summary of the status command.
+use crate::commands::goal::{goal_name, goal_time_interval};
+use crate::format_hours_and_minutes;
+use crate::models::{Goal, Period, Subject};
+use colored::Colorize;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::SqliteConnection;
+
+/// Number of columns of the progress bars.
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Prints the progress towards a goal with a progress bar and the remaining time.
+/// # Arguments
+/// * `label` - Label of the goal (e.g.: today).
+/// * `time` - Time studied.
+/// * `goal` - Time to study.
+fn print_progress(label: &str, time: i32, goal: i32) {
+    let filled = match goal {
+        0 => PROGRESS_BAR_WIDTH,
+        g => (time as usize * PROGRESS_BAR_WIDTH / g as usize).min(PROGRESS_BAR_WIDTH),
+    };
+    let remaining = match goal - time {
+        r if r > 0 => format!("{} left", format_hours_and_minutes(r)).normal(),
+        _ => "done!".green(),
+    };
+    println!(
+        "\t   {:<9} [{}{}] {} / {} ({})",
+        label,
+        "█".repeat(filled),
+        "░".repeat(PROGRESS_BAR_WIDTH - filled),
+        format_hours_and_minutes(time),
+        format_hours_and_minutes(goal),
+        remaining
+    );
+}
+
+/// Prints the progress towards the daily and weekly goals in effect on a date.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `period` - Period of the goals.
+/// * `date` - Date of the status.
+/// * `week_interval` - Interval of the week of the status.
+pub(crate) fn goals_summary(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    date: NaiveDate,
+    week_interval: (NaiveDate, NaiveDate),
+) {
+    println!("\tGoals:");
+    let mut owners: Vec<Option<Subject>> = vec![None];
+    owners.extend(
+        period
+            .fetch_subjects(conn)
+            .into_iter()
+            .filter(|s| !s.is_archived(date))
+            .map(Some),
+    );
+    for owner in owners {
+        let goal = match Goal::effective(conn, period.id, owner.as_ref().map(|s| s.id), date) {
+            Some(g) if g.daily_time.is_some() || g.weekly_time.is_some() => g,
+            _ => continue,
+        };
+        println!("\t * {}", goal_name(owner.as_ref()));
+        if let Some(daily) = goal.daily_time {
+            let time = goal_time_interval(conn, period, owner.as_ref(), (date, date));
+            print_progress("today", time, daily);
+        }
+        if let Some(weekly) = goal.weekly_time {
+            let time = goal_time_interval(conn, period, owner.as_ref(), week_interval);
+            print_progress("this week", time, weekly);
+        }
+    }
+}
@@ -1,20 +1,31 @@
 //! # Summary of study time.
 //! The status command's prompt is divided in three parts:
-//! * **Period details**: Prints the date and description of the period.
+//! * **Period details**: Prints the date and description of the period, and the current phase (if any).
 //! * **Daily summary**: Prints a daily summary, with the total time the user studied, and the time dedicated to every subject.
-//! * **Weekly summary**: Prints a weekly summary (if the previous week is included in the plan's period), with how much more did the user study regards the previous week, and whether they are doing better in their average.
+//! * **Weekly summary**: Prints a weekly summary (if the previous week is included in the plan's period), with how much more did the user study regards the previous week, and whether they are doing better in their average. Weeks fully inside a break of the plan are labeled, and they are left out of the comparisons.
+//! * **Upcoming exams**: If there are upcoming exams, prints a countdown and the time studied for each since the previous one.
+//! * **Goals summary**: If the period has goals, prints the progress towards the daily and weekly goals in effect.
+//!
+//! If the `--chart` option is given, a bar chart of the week and the sparklines of the last weeks are printed too.
 
 mod daily_summary;
+mod exams_summary;
+mod focus_summary;
+mod goals_summary;
 mod period_details;
 mod weekly_summary;
 
+use crate::commands::chart::{print_bar_chart, print_sparklines, DEFAULT_SPARKLINE_WEEKS};
 use crate::commands::status::daily_summary::daily_summary;
+use crate::commands::status::exams_summary::exams_summary;
+use crate::commands::status::focus_summary::focus_summary;
+use crate::commands::status::goals_summary::goals_summary;
 use crate::commands::status::period_details::print_period_details;
 use crate::commands::status::weekly_summary::weekly_summary;
-use crate::debug_println;
-use crate::interpreter::parse_date;
-use crate::models::{Period, Subject};
-use diesel::internal::derives::multiconnection::chrono::{Local, NaiveDate, TimeDelta, Weekday};
+use crate::interpreter::{parse_date, take_specific_arg};
+use crate::models::{Break, Entry, Exam, Goal, GroupBy, Period, Phase, Subject};
+use crate::{debug_println, today, FORMAT};
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, TimeDelta, Weekday};
 use diesel::SqliteConnection;
 use std::process;
 use terminal_size::{terminal_size, Width};
@@ -22,13 +33,13 @@ use terminal_size::{terminal_size, Width};
 /// Day considered the first of the week.
 pub const WEEKDAY_START: Weekday = Weekday::Mon;
 
-fn print_separator() {
+fn print_separator(character: char) {
     let line_longitude = match terminal_size() {
         Some((Width(w), _)) => 3 * w as i32 / 5,
         None => 30,
     };
     for _ in 0..line_longitude {
-        print!("-");
+        print!("{character}");
     }
     println!();
 }
@@ -37,47 +48,118 @@ fn print_separator() {
 /// # Arguments:
 /// * `conn` - Database connection.
 /// * `args` - Program arguments.
-pub fn display_status(conn: &mut SqliteConnection, args: &mut [String]) {
-    let date = match args.is_empty() {
-        true => Local::now().naive_local().date(),
-        false => parse_date(args.first().unwrap().trim()),
-    };
-    let period = match Period::from_date(conn, &date) {
-        Some(p) => p,
-        None => {
-            println!("There is no plan for this date.");
+pub fn display_status(conn: &mut SqliteConnection, args: &mut Vec<String>) {
+    let by = match take_specific_arg(args, "--by").map(|b| b.parse::<GroupBy>()) {
+        Some(Ok(by)) => by,
+        Some(Err(e)) => {
+            eprintln!("{e}");
             process::exit(1);
         }
+        None => GroupBy::Subject,
+    };
+    let chart = args.contains(&"--chart".to_string());
+    let date = match args.iter().find(|a| !a.starts_with("--")) {
+        Some(d) => parse_date(d.trim()),
+        None => today(),
     };
+    let active = Period::fetch_active(conn, &date);
+    if active.is_empty() {
+        println!("There is no plan for this date.");
+        process::exit(1);
+    }
+    // Every plan ocurring on the date is summarized, one after another.
+    for (i, period) in active.iter().enumerate() {
+        if i > 0 {
+            println!();
+            print_separator('=');
+        }
+        display_plan_status(conn, period, date, chart, by);
+    }
+}
+
+/// Displays the status of a plan on a date.
+/// # Arguments:
+/// * `conn` - Database connection.
+/// * `period` - Plan to display.
+/// * `date` - Date of the status.
+/// * `chart` - Whether to print the charts too.
+/// * `by` - How the subjects are grouped in the summaries.
+fn display_plan_status(
+    conn: &mut SqliteConnection,
+    period: &Period,
+    date: NaiveDate,
+    chart: bool,
+    by: GroupBy,
+) {
     println!("Current plan: {} (ID:{})", period.description, period.id);
-    print_period_details(&period, &date);
-    print_separator();
+    print_period_details(period, &date);
+    if let Some(phase) = Phase::on(&Phase::fetch_by_period(conn, period.id), date) {
+        println!(
+            "Current phase: {} (week {} of {}, until {})",
+            phase.name,
+            phase.week_of(date),
+            phase.weeks(),
+            phase.end_date.format(FORMAT)
+        );
+    }
+    print_separator('-');
     {
         let mut times: Vec<(Subject, i32)> = Vec::new();
-        for i in period.fetch_subjects(conn) {
+        // Archived subjects are hidden, since no time can be logged to them anymore.
+        for i in period
+            .fetch_subjects(conn)
+            .into_iter()
+            .filter(|s| !s.is_archived(date))
+        {
             let time = i.total_dedicated_time_day(date, conn);
             times.push((i, time));
         }
         let total_time_studied = times.iter().map(|(_, t)| t).sum::<i32>();
-        daily_summary(total_time_studied, &times);
-    }
-    print_separator();
-    {
-        let now_week = date.week(WEEKDAY_START);
-        let now_week_interval = (
-            now_week.first_day().max(period.initial_date),
-            now_week.last_day().min(period.final_date),
+        daily_summary(
+            total_time_studied,
+            &by.group(conn, &times, (date, date), |s| s.name.clone()),
         );
+        let ids: Vec<i32> = times.iter().map(|(s, _)| s.id).collect();
+        focus_summary(&Entry::fetch_by_subjects(
+            conn,
+            &ids,
+            (Some(date), Some(date)),
+        ));
+    }
+    print_separator('-');
+    let now_week = date.week(WEEKDAY_START);
+    let now_week_interval = (
+        now_week.first_day().max(period.initial_date),
+        now_week.last_day().min(period.end_date()),
+    );
+    let week_times = {
         let mut times: Vec<(Subject, i32)> = Vec::new();
         let subject_list = period.fetch_subjects(conn);
-        let previous_day = now_week.first_day().pred_opt().unwrap();
+        let breaks = Break::fetch_by_period(conn, period.id);
+        // Weeks fully inside a break are skipped, so the week is compared with the last teaching week.
+        let mut previous_day = now_week.first_day().pred_opt().unwrap();
+        let mut skipped_break: Option<String> = None;
+        while previous_day > period.initial_date {
+            let previous_week = previous_day.week(WEEKDAY_START);
+            let previous_interval = (
+                previous_week.first_day().max(period.initial_date),
+                previous_week.last_day().min(period.end_date()),
+            );
+            match Break::covering_label(&breaks, previous_interval) {
+                Some(label) => {
+                    skipped_break.get_or_insert(label);
+                    previous_day = previous_week.first_day().pred_opt().unwrap();
+                }
+                None => break,
+            }
+        }
         let total_previous_time: Option<i32>;
         let last_week_final_day: Option<NaiveDate>;
         if previous_day > period.initial_date {
             let previous_week = previous_day.week(WEEKDAY_START);
             let previous_interval = (
                 previous_week.first_day().max(period.initial_date),
-                previous_week.last_day().min(period.final_date),
+                previous_week.last_day().min(period.end_date()),
             );
             let mut total = 0;
             for i in &subject_list {
@@ -89,16 +171,21 @@ pub fn display_status(conn: &mut SqliteConnection, args: &mut [String]) {
             total_previous_time = None;
             last_week_final_day = None;
         }
+        // Subjects archived during the week are kept if they were studied before.
         for i in subject_list {
             let time = i.total_dedicated_time_interval(conn, now_week_interval);
-            times.push((i, time));
+            if time > 0 || !i.is_archived(date) {
+                times.push((i, time));
+            }
         }
         let total_time_studied = times.iter().map(|(_, t)| t).sum::<i32>();
         debug_println!("last_week: {:?}. Actual date: {date}", last_week_final_day);
         weekly_summary(
             total_time_studied,
-            &times,
+            &by.group(conn, &times, now_week_interval, |s| s.name.clone()),
+            Break::covering_label(&breaks, now_week_interval),
             total_previous_time,
+            skipped_break,
             match last_week_final_day {
                 Some(d) => {
                     debug_println!(
@@ -123,6 +210,31 @@ pub fn display_status(conn: &mut SqliteConnection, args: &mut [String]) {
                 }
                 None => None,
             },
-        )
+        );
+        let ids: Vec<i32> = times.iter().map(|(s, _)| s.id).collect();
+        let interval = (Some(now_week_interval.0), Some(now_week_interval.1));
+        focus_summary(&Entry::fetch_by_subjects(conn, &ids, interval));
+        times
+    };
+    let upcoming: Vec<(Exam, Subject)> = Exam::fetch_upcoming(conn, period, date)
+        .into_iter()
+        .filter(|(_, s)| !s.is_archived(date))
+        .collect();
+    if !upcoming.is_empty() {
+        print_separator('-');
+        exams_summary(conn, period, &upcoming, date);
+    }
+    if !Goal::fetch_by_period(conn, period.id).is_empty() {
+        print_separator('-');
+        goals_summary(conn, period, date, now_week_interval);
+    }
+    if chart {
+        print_separator('-');
+        println!("\tThis week by subject:");
+        print_bar_chart(&by.group(conn, &week_times, now_week_interval, |s| {
+            s.short_name.clone()
+        }));
+        println!();
+        print_sparklines(conn, period, date, DEFAULT_SPARKLINE_WEEKS);
     }
 }
@@ -21,10 +21,13 @@ pub fn print_period_details(period: &Period, date: &NaiveDate) {
         _ => print!(" - ... - {}", date.format(FORMAT).to_string().green()),
     }
 
-    match (period.final_date - *date).num_days() {
-        0 => {}
-        1 => print!(" - {}", period.final_date.format(FORMAT)),
-        _ => print!(" - ... - {}", period.final_date.format(FORMAT)),
+    match period.final_date {
+        Some(end) => match (end - *date).num_days() {
+            0 => {}
+            1 => print!(" - {}", end.format(FORMAT)),
+            _ => print!(" - ... - {}", end.format(FORMAT)),
+        },
+        None => print!(" - ... (open-ended)"),
     }
     println!();
 }
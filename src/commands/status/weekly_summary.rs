@@ -1,19 +1,30 @@
 //! Handles the weekly summary of the status command.
-use crate::models::Subject;
 use crate::{debug_println, format_hours_and_minutes};
+use colored::ColoredString;
 
 /// Prints the weekly summary of the status command.
 /// # Arguments
 /// * `total_time_studied` - Total time studied during the week.
-/// * `times` - List of (Subject, dedicated time during the week) tuples.
-/// * `last_week` - Total time studied the previous week (if any)
+/// * `times` - List of (subject or group name, dedicated time during the week) tuples.
+/// * `current_break` - Label of the break the week is inside of (if any).
+/// * `last_week` - Total time studied the previous teaching week (if any)
+/// * `skipped_break` - Label of the break between this week and the previous teaching week (if any).
 /// * `average` - Weekly average so far (if any).
 pub(crate) fn weekly_summary(
     total_time_studied: i32,
-    times: &Vec<(Subject, i32)>,
+    times: &[(ColoredString, i32)],
+    current_break: Option<String>,
     last_week: Option<i32>,
+    skipped_break: Option<String>,
     average: Option<f64>,
 ) {
+    if let Some(label) = &current_break {
+        println!("\tThis week is a break ({label}).");
+    }
+    let last_week_name = match &skipped_break {
+        Some(label) => format!("the last teaching week (before {label})"),
+        None => String::from("last week"),
+    };
     match total_time_studied {
         0 => {
             println!("\tYou haven't even study this week either!");
@@ -28,7 +39,7 @@ pub(crate) fn weekly_summary(
                     println!(
                         "\t * {} were dedicated to {}",
                         format_hours_and_minutes(i.1),
-                        i.0.name
+                        i.0
                     );
                 }
             }
@@ -39,30 +50,39 @@ pub(crate) fn weekly_summary(
                     match total_time_studied as f32 / (last_week as f32) {
                         k @ 0.0..1.0 => {
                             println!(
-                                "\t - You've studied {:.1}% less than last week.",
-                                (1.0 - k) * 100.0
+                                "\t - You've studied {:.1}% less than {}.",
+                                (1.0 - k) * 100.0,
+                                last_week_name
                             );
                         }
                         1.0 => {
-                            println!("\t - You've studied just the same as last week.");
+                            println!("\t - You've studied just the same as {}.", last_week_name);
                         }
                         k @ 1.0..=f32::MAX => {
                             println!(
-                                "\t - You've studied {:.1}% more than last week.",
-                                (k - 1.0) * 100.0
+                                "\t - You've studied {:.1}% more than {}.",
+                                (k - 1.0) * 100.0,
+                                last_week_name
                             );
                         }
                         k => {
                             debug_println!("Unexpected value at division: {k}");
                         }
                     }
+                } else if skipped_break.is_some() {
+                    println!(
+                        "\t - Definitely better than {}. (You didn't studied at all then)",
+                        last_week_name
+                    );
                 } else {
                     println!(
                         "\t - Definitely better than last week. (Last week you didn't studied at all)"
                     );
                 }
             }
-            if let Some(average) = average {
+            if current_break.is_some() {
+                println!("\t - Break weeks aren't compared with the weekly average.");
+            } else if let Some(average) = average {
                 debug_println!("avg: {average}");
                 if average != 0f64 {
                     match total_time_studied as f64 / average {
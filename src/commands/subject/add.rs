@@ -1,7 +1,7 @@
 //! Handles subject adding.
 use crate::models::Subject;
 use crate::schema::subjects::dsl::subjects;
-use crate::schema::subjects::{name, period_id, short_name};
+use crate::schema::subjects::{color, name, period_id, short_name};
 use diesel::dsl::insert_into;
 use diesel::ExpressionMethods;
 use diesel::{RunQueryDsl, SqliteConnection};
@@ -28,6 +28,7 @@ pub fn add(conn: &mut SqliteConnection, new_plan: i32, new_short_name: String, n
             short_name.eq(new_short_name),
             name.eq(new_name),
             period_id.eq(new_plan),
+            color.eq(Subject::next_color(conn, new_plan)),
         ))
         .execute(conn)
     {
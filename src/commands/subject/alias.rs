@@ -0,0 +1,87 @@
+//! Handles subject aliases: other names a subject can be referred to by in any command (e.g.: "algebra" for "Alg").
+
+use crate::models::{Period, Subject, SubjectAlias};
+use crate::schema::subject_aliases::dsl::subject_aliases;
+use crate::schema::subject_aliases::{alias, id, subject_id};
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, insert_into, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Adds an alias to a subject. Aliases are unique in a period (ignoring case), and can't clash with short names.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject of the alias.
+/// * `new_alias` - Alias to add.
+pub fn add(conn: &mut SqliteConnection, subject: &Subject, new_alias: String) {
+    if new_alias.parse::<i32>().is_ok() {
+        eprintln!("An alias can't be a number");
+        process::exit(1);
+    }
+    let period_subjects = match Period::from_id(conn, subject.period_id) {
+        Some(p) => p.fetch_subjects(conn),
+        None => {
+            eprintln!("Failed to fetch the period of the subject");
+            process::exit(1);
+        }
+    };
+    let ids: Vec<i32> = period_subjects.iter().map(|s| s.id).collect();
+    let lowercase = new_alias.to_lowercase();
+    let taken = period_subjects
+        .iter()
+        .find(|s| s.short_name.to_lowercase() == lowercase)
+        .cloned()
+        .or_else(|| {
+            SubjectAlias::fetch_for(conn, &ids)
+                .iter()
+                .find(|a| a.alias.to_lowercase() == lowercase)
+                .and_then(|a| period_subjects.iter().find(|s| s.id == a.subject_id))
+                .cloned()
+        });
+    if let Some(s) = taken {
+        eprintln!(
+            "'{new_alias}' is already used by {} ({}) in the period",
+            s.name, s.short_name
+        );
+        process::exit(1);
+    }
+    match insert_into(subject_aliases)
+        .values((subject_id.eq(subject.id), alias.eq(&new_alias)))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!("{} can now be referred to as '{new_alias}'", subject.name);
+        }
+        Err(e) => {
+            eprintln!("Failed to add the alias: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Removes an alias from a subject.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject of the alias.
+/// * `old_alias` - Alias to remove (ignoring case).
+pub fn remove(conn: &mut SqliteConnection, subject: &Subject, old_alias: &str) {
+    let to_remove = match SubjectAlias::fetch_for(conn, &[subject.id])
+        .into_iter()
+        .find(|a| a.alias.to_lowercase() == old_alias.to_lowercase())
+    {
+        Some(a) => a,
+        None => {
+            eprintln!("{} has no alias '{old_alias}'", subject.name);
+            process::exit(1);
+        }
+    };
+    match delete(subject_aliases.filter(id.eq(to_remove.id))).execute(conn) {
+        Ok(_) => {
+            println!("Alias removed succesfully");
+        }
+        Err(e) => {
+            eprintln!("Failed to remove the alias: {e}");
+            process::exit(1);
+        }
+    }
+}
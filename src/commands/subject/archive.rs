@@ -0,0 +1,85 @@
+//! Handles subject archival: subjects that are no longer taken (e.g.: dropped mid-period) are hidden from the status
+//! and no time can be logged to them, but their history is kept in reports and exports.
+
+use crate::models::Subject;
+use crate::schema::entry::dsl::entry;
+use crate::schema::entry::{date, subject_id};
+use crate::schema::subjects::dsl::subjects;
+use crate::schema::subjects::{archived_at, id};
+use crate::FORMAT;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::ExpressionMethods;
+use diesel::{update, QueryDsl, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Archives a subject from a date on.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject to archive.
+/// * `since` - First day the subject is archived.
+pub fn archive(conn: &mut SqliteConnection, subject: &Subject, since: NaiveDate) {
+    if let Some(a) = subject.archived_at {
+        eprintln!(
+            "{} is already archived since {}",
+            subject.name,
+            a.format(FORMAT)
+        );
+        process::exit(1);
+    }
+    // Time logged from the archive date on would be hidden, so it must be moved or removed first.
+    let later = match entry
+        .filter(subject_id.eq(subject.id))
+        .filter(date.ge(since))
+        .count()
+        .get_result::<i64>(conn)
+    {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Failed to fetch the entries of the subject: {e}");
+            process::exit(1);
+        }
+    };
+    if later > 0 {
+        eprintln!(
+            "{} has time logged on or after {}. Archive it from a later date, or remove that time first.",
+            subject.name,
+            since.format(FORMAT)
+        );
+        process::exit(1);
+    }
+    match update(subjects.filter(id.eq(subject.id)))
+        .set(archived_at.eq(Some(since)))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!("{} archived since {}", subject.name, since.format(FORMAT));
+        }
+        Err(e) => {
+            eprintln!("Failed to archive the subject: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Unarchives a subject, so it is taken again.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject to unarchive.
+pub fn unarchive(conn: &mut SqliteConnection, subject: &Subject) {
+    if subject.archived_at.is_none() {
+        eprintln!("{} isn't archived.", subject.name);
+        process::exit(1);
+    }
+    match update(subjects.filter(id.eq(subject.id)))
+        .set(archived_at.eq(None::<NaiveDate>))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!("{} unarchived succesfully", subject.name);
+        }
+        Err(e) => {
+            eprintln!("Failed to unarchive the subject: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Handles assessment adding.
+use crate::models::Subject;
+use crate::schema::assessments::dsl::assessments;
+use crate::schema::assessments::{date, max_score, min_score, name, subject_id, weight};
+use diesel::dsl::insert_into;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::ExpressionMethods;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Adds a new assessment to a subject.
+/// # Arguments
+/// * `conn` - Database connection
+/// * `subject` - Subject of the assessment.
+/// * `new_name` - Name of the assessment.
+/// * `new_weight` - Weight of the assessment, relative to the other assessments of the subject.
+/// * `new_max_score` - Maximum score of the assessment.
+/// * `new_min_score` - Minimum score required to pass the subject, if any.
+/// * `new_date` - Date of the assessment, if any.
+pub fn add(
+    conn: &mut SqliteConnection,
+    subject: &Subject,
+    new_name: String,
+    new_weight: f32,
+    new_max_score: f32,
+    new_min_score: Option<f32>,
+    new_date: Option<NaiveDate>,
+) {
+    match insert_into(assessments)
+        .values((
+            subject_id.eq(subject.id),
+            name.eq(new_name),
+            weight.eq(new_weight),
+            max_score.eq(new_max_score),
+            min_score.eq(new_min_score),
+            date.eq(new_date),
+        ))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!("Assessment added succesfully");
+        }
+        Err(e) => {
+            eprintln!("Could not insert assessment into database: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Handles commands related to the graded assessments of subjects.
+
+mod add;
+mod list;
+mod remove;
+mod score;
+
+use crate::commands::subject::get_plan_subject;
+use crate::commands::subject::usage::display_bad_usage;
+use crate::debug_println;
+use crate::interpreter::{detect_unknown_arg, parse_date, take_specific_arg};
+use crate::models::Assessment;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Gets the value of a score option, removing it from the arguments.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `find` - Option to find.
+fn take_score_arg(args: &mut Vec<String>, find: &str) -> Option<f32> {
+    if !args.contains(&find.to_string()) {
+        return None;
+    }
+    match take_specific_arg(args, find).map(|s| s.parse::<f32>()) {
+        Some(Ok(s)) if s >= 0.0 => Some(s),
+        _ => {
+            eprintln!(
+                "The value of {find} must be a positive decimal or integer number (e.g.: 5.2)"
+            );
+            process::exit(1);
+        }
+    }
+}
+
+/// Interprets assessment subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+/// * `plan_id` - Id of the current/specified plan.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection, plan_id: i32) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    match option.trim() {
+        "add" => {
+            if let Some(o) = detect_unknown_arg(args, &vec!["--max", "--min", "--date"], "--") {
+                eprintln!("Unknown argument: {o}");
+                display_bad_usage();
+                process::exit(1);
+            }
+            let max_score = take_score_arg(args, "--max").unwrap_or(10.0);
+            let min_score = take_score_arg(args, "--min");
+            let date = match args.contains(&"--date".to_string()) {
+                true => match take_specific_arg(args, "--date") {
+                    Some(d) => Some(parse_date(d.trim())),
+                    None => {
+                        display_bad_usage();
+                        process::exit(1);
+                    }
+                },
+                false => None,
+            };
+            if args.len() < 3 {
+                display_bad_usage();
+                process::exit(1);
+            }
+            let subject = get_plan_subject(&args[0], conn, plan_id);
+            let weight = match args[1].trim_end_matches('%').parse::<f32>() {
+                Ok(w) if w > 0.0 => w,
+                _ => {
+                    eprintln!("Weight must be a positive decimal or integer number (e.g.: 30)");
+                    process::exit(1);
+                }
+            };
+            if max_score <= 0.0 || min_score.is_some_and(|m| m > max_score) {
+                eprintln!("The minimum score must be between zero and the maximum score");
+                process::exit(1);
+            }
+            let name = args.split_at(2).1.join(" ");
+            add::add(conn, &subject, name, weight, max_score, min_score, date);
+        }
+        "score" | "unscore" => {
+            let assessment = match args.first().map(|i| i.parse::<i32>()) {
+                Some(Ok(assessment_id)) => match Assessment::from_id(conn, assessment_id) {
+                    Some(a) => a,
+                    None => {
+                        eprintln!("There is no assessment with id {assessment_id}");
+                        process::exit(1);
+                    }
+                },
+                _ => {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+            };
+            let new_score = match option.trim() {
+                "score" => match args.get(1).map(|s| s.parse::<f32>()) {
+                    Some(Ok(s)) if (0.0..=assessment.max_score).contains(&s) => Some(s),
+                    Some(_) => {
+                        eprintln!(
+                            "Score must be a number between 0 and {}",
+                            assessment.max_score
+                        );
+                        process::exit(1);
+                    }
+                    None => {
+                        display_bad_usage();
+                        process::exit(1);
+                    }
+                },
+                _ => None,
+            };
+            score::update_score(conn, assessment, new_score);
+        }
+        "list" => {
+            let subject = args.first().map(|s| get_plan_subject(s, conn, plan_id));
+            list::list(conn, plan_id, subject);
+        }
+        "remove" => match args.first().map(|i| i.parse::<i32>()) {
+            Some(Ok(assessment_id)) => remove::remove(conn, assessment_id),
+            _ => {
+                display_bad_usage();
+                process::exit(1);
+            }
+        },
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Handles assessment removal.
+use crate::schema::assessments::dsl::assessments;
+use crate::schema::assessments::id;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Removes an existing assessment.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `assessment_id` - Assessment's id.
+pub fn remove(conn: &mut SqliteConnection, assessment_id: i32) {
+    match delete(assessments.filter(id.eq(assessment_id))).execute(conn) {
+        Ok(0) => {
+            eprintln!("There is no assessment with id {assessment_id}");
+            process::exit(1);
+        }
+        Ok(_) => {
+            println!("Assessment removed succesfully");
+        }
+        Err(e) => {
+            eprintln!("Error deleting assessment: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Handles assessment scoring.
+use crate::models::Assessment;
+use crate::schema::assessments::dsl::assessments;
+use crate::schema::assessments::{id, score};
+use diesel::ExpressionMethods;
+use diesel::{update, QueryDsl, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// It updates an assessment's score.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `assessment` - Assessment to modify its score.
+/// * `new_score` - New score, where None means the assessment has no score yet.
+pub fn update_score(conn: &mut SqliteConnection, assessment: Assessment, new_score: Option<f32>) {
+    match update(assessments.filter(id.eq(assessment.id)))
+        .set(score.eq(new_score))
+        .execute(conn)
+    {
+        Ok(_) => match new_score {
+            Some(s) => {
+                println!(
+                    "Successfully scored {} with {}/{}",
+                    assessment.name, s, assessment.max_score
+                );
+            }
+            None => {
+                println!("Successfully unscored {}", assessment.name);
+            }
+        },
+        Err(e) => {
+            eprintln!("Failed scoring: {e}");
+            process::exit(1);
+        }
+    }
+}
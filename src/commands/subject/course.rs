@@ -0,0 +1,148 @@
+//! Handles courses: subjects of different periods linked as the same course (e.g.: a year-long subject), whose
+//! time, goals and grades are computed across the whole course.
+use crate::commands::gpa::Totals;
+use crate::models::{Goal, Period, Subject};
+use crate::schema::subjects::dsl::subjects;
+use crate::schema::subjects::{course_id, id};
+use crate::{format_hours_and_minutes, today};
+use colored::Colorize;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{update, Connection, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Links a subject to the course of another one. If both were already in courses, they are merged into one.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject the course is joined to.
+/// * `other` - Subject to link.
+pub fn link(conn: &mut SqliteConnection, subject: &Subject, other: &Subject) {
+    let course = subject.fetch_course(conn);
+    let other_course = other.fetch_course(conn);
+    if course.iter().any(|s| s.id == other.id) {
+        eprintln!("Both subjects are already the same course.");
+        process::exit(1);
+    }
+    // A course has at most one subject per period, so the time of a day is never counted twice.
+    if let Some(s) = other_course
+        .iter()
+        .find(|o| course.iter().any(|c| c.period_id == o.period_id))
+    {
+        eprintln!(
+            "The course already has a subject in the period of {} ({}).",
+            s.name, s.short_name
+        );
+        process::exit(1);
+    }
+    let key = subject.course_id.unwrap_or(subject.id);
+    let linked: Vec<i32> = other_course.iter().map(|s| s.id).collect();
+    match conn.transaction(|conn| {
+        update(subjects.filter(id.eq(subject.id)))
+            .set(course_id.eq(key))
+            .execute(conn)?;
+        update(subjects.filter(id.eq_any(&linked)))
+            .set(course_id.eq(key))
+            .execute(conn)
+    }) {
+        Ok(_) => {
+            println!(
+                "{} is now the same course as {} ({} subjects)",
+                other.name,
+                subject.name,
+                course.len() + other_course.len()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to link the subjects: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Removes a subject from its course.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject to unlink.
+pub fn unlink(conn: &mut SqliteConnection, subject: &Subject) {
+    let course = subject.fetch_course(conn);
+    if course.len() < 2 {
+        eprintln!("{} isn't linked to any other subject.", subject.name);
+        process::exit(1);
+    }
+    // A course of one subject isn't a course anymore.
+    let mut unlinked = vec![subject.id];
+    if course.len() == 2 {
+        unlinked.extend(course.iter().map(|s| s.id));
+    }
+    match update(subjects.filter(id.eq_any(&unlinked)))
+        .set(course_id.eq(None::<i32>))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!("{} unlinked succesfully", subject.name);
+        }
+        Err(e) => {
+            eprintln!("Failed to unlink the subject: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Shows the time, goals and grades of the course of a subject, period by period and for the whole course.
+/// Marks are converted to the grading scale of the last period of the course.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject of the course.
+pub fn show(conn: &mut SqliteConnection, subject: &Subject) {
+    let course = subject.fetch_course(conn);
+    let today = today();
+    let mut course_periods: Vec<Period> = Vec::new();
+    for s in &course {
+        match Period::from_id(conn, s.period_id) {
+            Some(p) => course_periods.push(p),
+            None => {
+                eprintln!("Failed to fetch the period of {}", s.short_name);
+                process::exit(1);
+            }
+        }
+    }
+    let scale = course_periods.last().unwrap().scale();
+    println!("Course of {} ({} periods):", subject.name, course.len());
+    let mut totals = Totals::default();
+    let mut total_time = 0;
+    let mut total_weeks = 0.0;
+    for (s, period) in course.iter().zip(&course_periods) {
+        let time = s.total_dedicated_time(conn);
+        let last_day = period.end_date().min(today).max(period.initial_date);
+        let weeks = ((last_day - period.initial_date).num_days() + 1) as f64 / 7.0;
+        let mut line = format!(
+            "\t * {} in {}: {} ({}/week)",
+            s.short_name,
+            period.description,
+            format_hours_and_minutes(time),
+            format_hours_and_minutes((time as f64 / weeks.max(1.0)).round() as i32)
+        );
+        if let Some(weekly) =
+            Goal::effective(conn, period.id, Some(s.id), last_day).and_then(|g| g.weekly_time)
+        {
+            line.push_str(&format!(
+                ", weekly goal {}",
+                format_hours_and_minutes(weekly)
+            ));
+        }
+        if let Some(mark) = s.final_score {
+            line.push_str(&format!(", mark {}", period.scale().format_score(mark)));
+        }
+        println!("{line}");
+        let mark = s.final_score.map(|m| period.scale().convert(m, scale));
+        totals.add(s, mark, s.is_passed(period.scale()));
+        total_time += time;
+        total_weeks += weeks;
+    }
+    println!(
+        "\tWhole course: {} ({}/week)",
+        format_hours_and_minutes(total_time).bold(),
+        format_hours_and_minutes((total_time as f64 / total_weeks.max(1.0)).round() as i32)
+    );
+    totals.print("Course mark", scale);
+}
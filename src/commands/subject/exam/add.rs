@@ -0,0 +1,51 @@
+//! Handles exam adding.
+use crate::models::{Period, Subject};
+use crate::schema::exams::dsl::exams;
+use crate::schema::exams::{date, description, kind, subject_id, weight};
+use diesel::dsl::insert_into;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::ExpressionMethods;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Adds a new exam or deadline to a subject.
+/// # Arguments
+/// * `conn` - Database connection
+/// * `subject` - Subject of the exam.
+/// * `new_date` - Date of the exam.
+/// * `new_kind` - Kind of exam (e.g.: partial, final, lab, assignment).
+/// * `new_weight` - Weight of the exam in the final grade, as a percentage.
+/// * `new_description` - Description of the exam.
+pub fn add(
+    conn: &mut SqliteConnection,
+    subject: &Subject,
+    new_date: NaiveDate,
+    new_kind: String,
+    new_weight: Option<f32>,
+    new_description: Option<String>,
+) {
+    if let Some(period) = Period::from_id(conn, subject.period_id) {
+        if new_date < period.initial_date || new_date > period.end_date() {
+            eprintln!("The exam date must be inside the period of the subject.");
+            process::exit(1);
+        }
+    }
+    match insert_into(exams)
+        .values((
+            subject_id.eq(subject.id),
+            date.eq(new_date),
+            kind.eq(new_kind),
+            weight.eq(new_weight),
+            description.eq(new_description),
+        ))
+        .execute(conn)
+    {
+        Ok(_) => {
+            println!("Exam added succesfully");
+        }
+        Err(e) => {
+            eprintln!("Could not insert exam into database: {}", e);
+            process::exit(1);
+        }
+    }
+}
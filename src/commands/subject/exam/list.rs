@@ -0,0 +1,36 @@
+//! Handles exam listing.
+use crate::models::{Exam, Period, Subject};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Lists the exams of a subject, or of every subject of a plan.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `plan_id` - Id of the plan.
+/// * `subject` - Subject to list its exams. If None, every subject of the plan is listed.
+pub fn list(conn: &mut SqliteConnection, plan_id: i32, subject: Option<Subject>) {
+    let subject_list = match subject {
+        Some(s) => vec![s],
+        None => match Period::from_id(conn, plan_id) {
+            Some(period) => period.fetch_subjects(conn),
+            None => {
+                eprintln!("Failed fetching plan. Does this plan exists?");
+                process::exit(1);
+            }
+        },
+    };
+    let mut empty = true;
+    for s in subject_list {
+        let subject_exams = Exam::fetch_by_subject(conn, s.id);
+        if !subject_exams.is_empty() {
+            empty = false;
+            println!("{}:", s);
+            for e in subject_exams {
+                println!("\t{e}");
+            }
+        }
+    }
+    if empty {
+        println!("No exams registered yet.");
+    }
+}
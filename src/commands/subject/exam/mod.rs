@@ -0,0 +1,70 @@
+//! Handles commands related to the exams and deadlines of subjects.
+
+mod add;
+mod list;
+mod remove;
+
+use crate::commands::subject::get_plan_subject;
+use crate::commands::subject::usage::display_bad_usage;
+use crate::debug_println;
+use crate::interpreter::{parse_date, take_specific_arg};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Interprets exam subcommands.
+/// # Arguments
+/// * `args` - Remaining program arguments.
+/// * `conn` - Database connection.
+/// * `plan_id` - Id of the current/specified plan.
+pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection, plan_id: i32) {
+    if args.is_empty() {
+        display_bad_usage();
+        process::exit(1);
+    }
+    let option = args.first().cloned().unwrap();
+    args.remove(0);
+    match option.trim() {
+        "add" => {
+            let weight = match args.contains(&"--weight".to_string()) {
+                true => match take_specific_arg(args, "--weight")
+                    .map(|w| w.trim_end_matches('%').parse::<f32>())
+                {
+                    Some(Ok(w)) if w >= 0.0 => Some(w),
+                    _ => {
+                        eprintln!("Weight must be a positive decimal or integer number (e.g.: 30)");
+                        process::exit(1);
+                    }
+                },
+                false => None,
+            };
+            if args.len() < 3 {
+                display_bad_usage();
+                process::exit(1);
+            }
+            let subject = get_plan_subject(&args[0], conn, plan_id);
+            let date = parse_date(args[1].trim());
+            let kind = args[2].clone();
+            let description = match args.split_at(3).1.join(" ") {
+                d if d.is_empty() => None,
+                d => Some(d),
+            };
+            add::add(conn, &subject, date, kind, weight, description);
+        }
+        "list" => {
+            let subject = args.first().map(|s| get_plan_subject(s, conn, plan_id));
+            list::list(conn, plan_id, subject);
+        }
+        "remove" => match args.first().map(|i| i.parse::<i32>()) {
+            Some(Ok(exam_id)) => remove::remove(conn, exam_id),
+            _ => {
+                display_bad_usage();
+                process::exit(1);
+            }
+        },
+        k => {
+            debug_println!("No valid argument. Provided: {k}");
+            display_bad_usage();
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Handles exam removal.
+use crate::schema::exams::dsl::exams;
+use crate::schema::exams::id;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Removes an existing exam.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `exam_id` - Exam's id.
+pub fn remove(conn: &mut SqliteConnection, exam_id: i32) {
+    match delete(exams.filter(id.eq(exam_id))).execute(conn) {
+        Ok(0) => {
+            eprintln!("There is no exam with id {exam_id}");
+            process::exit(1);
+        }
+        Ok(_) => {
+            println!("Exam removed succesfully");
+        }
+        Err(e) => {
+            eprintln!("Error deleting exam: {e}");
+            process::exit(1);
+        }
+    }
+}
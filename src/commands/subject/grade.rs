@@ -0,0 +1,154 @@
+//! Handles the final grade of a subject, computed from its weighted assessments.
+use crate::commands::subject::mark::update_mark;
+use crate::models::{Assessment, GradingScale, Subject};
+use colored::Colorize;
+use diesel::SqliteConnection;
+use std::process;
+
+/// Fraction of the pass mark taken as the maximum final grade when the minimum score of an assessment isn't
+/// reached, so that the subject is failed.
+const FAILED_MINIMUM_CAP: f32 = 0.9;
+
+/// Prints the score needed in the remaining assessments to get a target grade, assuming the same relative
+/// performance in all of them.
+/// # Arguments
+/// * `assessments` - Assessments of the subject.
+/// * `earned` - Weighted relative score of the scored assessments.
+/// * `total_weight` - Sum of the weights of every assessment.
+/// * `scale` - Grading scale of the subject.
+/// * `target` - Grade to get.
+fn print_projection(
+    assessments: &[Assessment],
+    earned: f32,
+    total_weight: f32,
+    scale: GradingScale,
+    target: f32,
+) {
+    let remaining = assessments
+        .iter()
+        .filter(|a| a.score.is_none())
+        .collect::<Vec<&Assessment>>();
+    let remaining_weight = remaining.iter().map(|a| a.weight).sum::<f32>();
+    let needed = (target / scale.max() * total_weight - earned) / remaining_weight;
+    let target = scale.format_score(target);
+    if needed > 1.0 {
+        println!(
+            "\t{}",
+            format!("A {target} can't be reached anymore.").red()
+        );
+        return;
+    }
+    if needed <= 0.0 {
+        println!("\tYou already have a {target}, whatever the remaining scores are.");
+    } else if remaining.len() > 1 {
+        println!(
+            "\tTo get a {target}, you need {:.1}% of the score in each remaining assessment:",
+            needed * 100.0
+        );
+    } else {
+        println!("\tTo get a {target}, you need:");
+    }
+    for a in remaining {
+        let score = (needed.max(0.0) * a.max_score).max(a.min_score.unwrap_or(0.0));
+        if score > 0.0 {
+            println!("\t * {:.2}/{} in {}", score, a.max_score, a.name);
+        }
+    }
+}
+
+/// Prints the weighted grade of a subject computed from its assessments, checking the minimum scores (if one isn't
+/// reached, the final grade is capped below the pass mark). If every assessment is scored, the grade can be synced
+/// to the subject's final score.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject` - Subject to grade.
+/// * `scale` - Grading scale of the subject.
+/// * `target` - Grade to project in the remaining assessments.
+/// * `sync` - Whether the final score of the subject must be updated with the computed grade.
+pub fn grade(
+    conn: &mut SqliteConnection,
+    subject: Subject,
+    scale: GradingScale,
+    target: f32,
+    sync: bool,
+) {
+    let assessments = Assessment::fetch_by_subject(conn, subject.id);
+    if assessments.is_empty() {
+        eprintln!(
+            "{} has no assessments yet. Use 'subject assessment add' first.",
+            subject.short_name
+        );
+        process::exit(1);
+    }
+    println!("{}", subject);
+    for a in &assessments {
+        println!("\t * {a}");
+    }
+    println!();
+    let total_weight = assessments.iter().map(|a| a.weight).sum::<f32>();
+    let scored_weight = assessments
+        .iter()
+        .filter(|a| a.score.is_some())
+        .map(|a| a.weight)
+        .sum::<f32>();
+    let earned = assessments
+        .iter()
+        .filter_map(|a| a.relative_score().map(|s| s * a.weight))
+        .sum::<f32>();
+    if scored_weight > 0.0 && scored_weight < total_weight {
+        println!(
+            "\tAverage of the scored assessments ({:.1}% of the subject): {}",
+            scored_weight * 100.0 / total_weight,
+            scale.format_score(earned / scored_weight * scale.max())
+        );
+    }
+    let below_minimum = assessments
+        .iter()
+        .filter(|a| a.below_minimum())
+        .collect::<Vec<&Assessment>>();
+    for a in &below_minimum {
+        println!(
+            "\t{}",
+            format!(
+                "The minimum score of {} in {} wasn't reached.",
+                a.min_score.unwrap(),
+                a.name
+            )
+            .red()
+        );
+    }
+    let grade = earned / total_weight * scale.max();
+    if scored_weight < total_weight {
+        println!(
+            "\tPoints earned so far: {:.2} out of {}",
+            grade,
+            scale.max()
+        );
+        if below_minimum.is_empty() {
+            print_projection(&assessments, earned, total_weight, scale, target);
+        }
+        if sync {
+            eprintln!("The final score can't be synced until every assessment is scored.");
+            process::exit(1);
+        }
+    } else {
+        let grade = match below_minimum.is_empty() {
+            true => grade,
+            false => grade.min(scale.pass_mark() * FAILED_MINIMUM_CAP),
+        };
+        // Letter grades can only be synced as whole grade points.
+        let grade = match scale {
+            GradingScale::Letter => grade.floor(),
+            _ => (grade * 100.0).round() / 100.0,
+        };
+        let line = format!("\tFinal grade: {}", scale.format_score(grade));
+        if scale.passes(grade) {
+            println!("{}", line.green());
+        } else {
+            println!("{}", line.red());
+        }
+        if sync {
+            update_mark(conn, subject, Some(grade));
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Handles subject listing.
-use crate::format_hours_and_minutes;
-use crate::models::{Period, Subject};
+use crate::models::{Period, Subject, SubjectAlias};
+use crate::{format_hours_and_minutes, FORMAT};
 use diesel::SqliteConnection;
 use std::process;
 
@@ -21,7 +21,9 @@ pub fn list(conn: &mut SqliteConnection, plan_id: i32) {
     };
     println!(
         "Subjects from period {} ({} - {})",
-        plan.description, plan.initial_date, plan.final_date
+        plan.description,
+        plan.initial_date.format(FORMAT),
+        plan.format_end()
     );
     let subjects_from_plan = Subject::fetch_all(conn)
         .iter()
@@ -31,12 +33,53 @@ pub fn list(conn: &mut SqliteConnection, plan_id: i32) {
     if subjects_from_plan.is_empty() {
         println!("No subjects from this period");
     } else {
-        for s in subjects_from_plan {
-            println!(
-                "{}, TDT: {}",
-                s,
-                format_hours_and_minutes(s.total_dedicated_time(conn))
-            );
+        let ids: Vec<i32> = subjects_from_plan.iter().map(|s| s.id).collect();
+        let aliases = SubjectAlias::fetch_for(conn, &ids);
+        for s in &subjects_from_plan {
+            let total = s.total_dedicated_time(conn);
+            let mut line = s.to_string();
+            let subject_aliases: Vec<&str> = aliases
+                .iter()
+                .filter(|a| a.subject_id == s.id)
+                .map(|a| a.alias.as_str())
+                .collect();
+            if !subject_aliases.is_empty() {
+                line.push_str(&format!(" aka {}", subject_aliases.join(", ")));
+            }
+            line.push_str(&format!(", TDT: {}", format_hours_and_minutes(total)));
+            if s.course_id.is_some() {
+                line.push_str(&format!(
+                    " (course: {})",
+                    format_hours_and_minutes(s.course_dedicated_time(conn))
+                ));
+            }
+            if let Some(credits) = s.credits {
+                line.push_str(&format!(", {} credits", credits));
+                if credits > 0.0 {
+                    line.push_str(&format!(
+                        " ({}/credit)",
+                        format_hours_and_minutes((total as f32 / credits).round() as i32)
+                    ));
+                }
+            }
+            if let Some(category) = s.effective_category(&subjects_from_plan) {
+                line.push_str(&format!(", category {category}"));
+            }
+            if let Some(parent) = s
+                .parent_id
+                .and_then(|p| subjects_from_plan.iter().find(|o| o.id == p))
+            {
+                line.push_str(&format!(", part of {}", parent.short_name));
+            }
+            if let Some(a) = s.archived_at {
+                line.push_str(&format!(", archived since {}", a.format(FORMAT)));
+            }
+            match s.is_passed(plan.scale()) {
+                Some(true) => line.push_str(", passed"),
+                Some(false) => line.push_str(", failed"),
+                None => {}
+            }
+            println!("{line}");
         }
     }
 }
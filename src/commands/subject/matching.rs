@@ -0,0 +1,143 @@
+//! Handles how subject arguments are matched: by short name, alias or name, ignoring case, or by a prefix of any of
+//! them that only one subject has. When nothing matches, the closest names are suggested.
+
+use crate::models::{Subject, SubjectAlias};
+use diesel::SqliteConnection;
+use std::process;
+
+/// Maximum number of suggestions given when no subject matches.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Result of matching a subject argument against a list of subjects.
+pub(crate) enum SubjectMatch {
+    /// Exactly one subject matches.
+    Found(Subject),
+    /// More than one subject matches.
+    Ambiguous(Vec<Subject>),
+    /// No subject matches. The closest ones are given, sorted by how close they are.
+    NotFound(Vec<Subject>),
+}
+
+/// Gets the Levenshtein distance between two strings (the number of single character insertions, deletions or
+/// substitutions needed to get one from the other).
+/// # Arguments
+/// * `a` - First string.
+/// * `b` - Second string.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Matches a subject argument against a list of subjects. In order, it looks for:
+/// 1. The exact short name or alias.
+/// 2. The short name, alias or name, ignoring case.
+/// 3. A prefix (ignoring case) of the short name, alias or name of only one subject.
+///
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `subject_arg` - Subject argument.
+/// * `list` - Subjects it may refer to.
+pub(crate) fn find_subject(
+    conn: &mut SqliteConnection,
+    subject_arg: &str,
+    list: Vec<Subject>,
+) -> SubjectMatch {
+    let ids: Vec<i32> = list.iter().map(|s| s.id).collect();
+    let aliases = SubjectAlias::fetch_for(conn, &ids);
+    let keys = |s: &Subject| -> Vec<String> {
+        let mut keys = vec![s.short_name.clone()];
+        keys.extend(
+            aliases
+                .iter()
+                .filter(|a| a.subject_id == s.id)
+                .map(|a| a.alias.clone()),
+        );
+        keys
+    };
+    let lowercase = subject_arg.trim().to_lowercase();
+    let stages: [&dyn Fn(&Subject) -> bool; 3] = [
+        &|s| keys(s).iter().any(|k| k == subject_arg.trim()),
+        &|s| {
+            keys(s)
+                .iter()
+                .chain([&s.name])
+                .any(|k| k.to_lowercase() == lowercase)
+        },
+        &|s| {
+            keys(s)
+                .iter()
+                .chain([&s.name])
+                .any(|k| k.to_lowercase().starts_with(&lowercase))
+        },
+    ];
+    for stage in stages {
+        let mut found: Vec<Subject> = list.iter().filter(|s| stage(s)).cloned().collect();
+        match found.len() {
+            0 => continue,
+            1 => return SubjectMatch::Found(found.pop().unwrap()),
+            _ => return SubjectMatch::Ambiguous(found),
+        }
+    }
+    // Typos are allowed to be about a third of the argument, and at least two characters.
+    let threshold = (lowercase.chars().count() / 3).max(2);
+    let mut close: Vec<(usize, Subject)> = list
+        .iter()
+        .filter_map(|s| {
+            keys(s)
+                .iter()
+                .chain([&s.name])
+                .map(|k| edit_distance(&k.to_lowercase(), &lowercase))
+                .min()
+                .filter(|d| *d <= threshold)
+                .map(|d| (d, s.clone()))
+        })
+        .collect();
+    close.sort_by_key(|(d, _)| *d);
+    SubjectMatch::NotFound(
+        close
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, s)| s)
+            .collect(),
+    )
+}
+
+/// Prints why a subject argument didn't match a single subject and exits.
+/// # Arguments
+/// * `subject_arg` - Subject argument.
+/// * `result` - Result of the match.
+pub(crate) fn exit_with_match_error(subject_arg: &str, result: SubjectMatch) -> ! {
+    match result {
+        SubjectMatch::Ambiguous(found) => {
+            eprintln!(
+                "'{subject_arg}' matches more than one subject. Use its id or short name instead:"
+            );
+            for s in found {
+                eprintln!("\t * {} ({}, ID:{})", s.name, s.short_name, s.id);
+            }
+        }
+        SubjectMatch::NotFound(close) if !close.is_empty() => {
+            let names: Vec<String> = close
+                .iter()
+                .map(|s| format!("{} ({})", s.short_name, s.name))
+                .collect();
+            eprintln!(
+                "There is no subject '{subject_arg}'. Did you mean {}?",
+                names.join(", ")
+            );
+        }
+        _ => {
+            eprintln!("There is no subject '{subject_arg}'.");
+        }
+    }
+    process::exit(1);
+}
@@ -0,0 +1,247 @@
+//! Handles moving entries between subjects: merging a subject into another (e.g.: "Alg" and "Algebra" created by
+//! mistake) and moving the entries of a date range to another subject.
+
+use crate::format_hours_and_minutes;
+use crate::models::{Entry, Period, Session, Subject};
+use crate::schema::entry::dsl::entry;
+use crate::schema::entry::{activity, date, dedicated_time, focus, note, subject_id, tags};
+use crate::schema::subjects::dsl::subjects;
+use crate::schema::subjects::{archived_at, id, parent_id};
+use crate::schema::{assessments, exams, goals, pomodoros, sessions, subject_aliases};
+use crate::{study_day, today, FORMAT};
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::ExpressionMethods;
+use diesel::{delete, update, Connection, QueryDsl, QueryResult, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Fetches the entries and sessions of a subject to move to another one, checking the other subject can have their
+/// time. Returns the entries and the ids of the sessions.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `from` - Subject whose entries are moved.
+/// * `to` - Subject the entries are moved to.
+/// * `interval` - Dates of the entries to move (first and last day, both included), where None means unbounded.
+fn prepare_transfer(
+    conn: &mut SqliteConnection,
+    from: &Subject,
+    to: &Subject,
+    interval: (Option<NaiveDate>, Option<NaiveDate>),
+) -> (Vec<Entry>, Vec<i32>) {
+    let mut query = entry.filter(subject_id.eq(from.id)).into_boxed();
+    if let Some(first) = interval.0 {
+        query = query.filter(date.ge(first));
+    }
+    if let Some(last) = interval.1 {
+        query = query.filter(date.le(last));
+    }
+    let moved = match query.order_by(date).load::<Entry>(conn) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("Failed to fetch the entries of {}: {e}", from.short_name);
+            process::exit(1);
+        }
+    };
+    let target_period = match Period::from_id(conn, to.period_id) {
+        Some(p) => p,
+        None => {
+            eprintln!("Failed to fetch the period of {}", to.short_name);
+            process::exit(1);
+        }
+    };
+    // Entries outside the period of the target wouldn't be counted, and archived subjects can't get new time.
+    if let Some(e) = moved
+        .iter()
+        .find(|e| !target_period.contains(e.date) || to.is_archived(e.date))
+    {
+        eprintln!(
+            "{} can't have time on {}, since it is outside its period or after it was archived.",
+            to.name,
+            e.date.format(FORMAT)
+        );
+        process::exit(1);
+    }
+    let moved_sessions: Vec<i32> = Session::fetch_by_subjects(conn, &[from.id])
+        .into_iter()
+        .filter(|s| {
+            let day = study_day(s.started_at);
+            interval.0.is_none_or(|first| day >= first) && interval.1.is_none_or(|last| day <= last)
+        })
+        .map(|s| s.id)
+        .collect();
+    (moved, moved_sessions)
+}
+
+/// Moves entries (and sessions) to another subject, adding up the time (and joining the notes, tags and focus) of
+/// the days the subject already has an entry of the same activity type. Meant to be run inside a transaction.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `to` - Subject the entries are moved to.
+/// * `moved` - Entries to move.
+/// * `moved_sessions` - Ids of the sessions to move.
+fn apply_transfer(
+    conn: &mut SqliteConnection,
+    to: &Subject,
+    moved: &[Entry],
+    moved_sessions: &[i32],
+) -> QueryResult<()> {
+    update(sessions::table.filter(sessions::id.eq_any(moved_sessions)))
+        .set(sessions::subject_id.eq(to.id))
+        .execute(conn)?;
+    for e in moved {
+        let existing = entry
+            .filter(subject_id.eq(to.id))
+            .filter(date.eq(e.date))
+            .filter(activity.eq(&e.activity))
+            .first::<Entry>(conn);
+        match existing {
+            Ok(kept) => {
+                let merged_note = match (&kept.note, &e.note) {
+                    (Some(k), Some(n)) => Some(format!("{k}; {n}")),
+                    (k, n) => k.clone().or(n.clone()),
+                };
+                let mut merged_tags = kept.tag_list();
+                for t in e.tag_list() {
+                    if !merged_tags.contains(&t) {
+                        merged_tags.push(t);
+                    }
+                }
+                let merged_tags = Some(merged_tags.join(",")).filter(|t| !t.is_empty());
+                // The focus is weighted by the time of each entry.
+                let merged_focus = match (kept.focus, e.focus) {
+                    (Some(k), Some(f)) if kept.dedicated_time + e.dedicated_time > 0 => Some(
+                        (k * kept.dedicated_time as f64 + f * e.dedicated_time as f64)
+                            / (kept.dedicated_time + e.dedicated_time) as f64,
+                    ),
+                    (k, f) => k.or(f),
+                };
+                update(entry.filter(crate::schema::entry::id.eq(kept.id)))
+                    .set((
+                        dedicated_time.eq(kept.dedicated_time + e.dedicated_time),
+                        note.eq(merged_note),
+                        tags.eq(merged_tags),
+                        focus.eq(merged_focus),
+                    ))
+                    .execute(conn)?;
+                delete(entry.filter(crate::schema::entry::id.eq(e.id))).execute(conn)?;
+            }
+            Err(diesel::NotFound) => {
+                update(entry.filter(crate::schema::entry::id.eq(e.id)))
+                    .set(subject_id.eq(to.id))
+                    .execute(conn)?;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+/// Prints how many entries were moved between two subjects and the time they add up to.
+/// # Arguments
+/// * `from` - Subject whose entries were moved.
+/// * `to` - Subject the entries were moved to.
+/// * `moved` - Moved entries.
+fn print_moved(from: &Subject, to: &Subject, moved: &[Entry]) {
+    println!(
+        "Moved {} entries ({}) from {} to {}",
+        moved.len(),
+        format_hours_and_minutes(moved.iter().map(|e| e.dedicated_time).sum()),
+        from.short_name,
+        to.short_name
+    );
+}
+
+/// Merges a subject into another one: its entries are moved to the other subject, and then it is archived or
+/// removed. When removed, its exams, assessments, pomodoros, aliases and child subjects are moved to the other subject
+/// too, and its goals are removed.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `from` - Subject to merge.
+/// * `into` - Subject it is merged into.
+/// * `keep` - Whether to archive the merged subject instead of removing it.
+pub fn merge(conn: &mut SqliteConnection, from: &Subject, into: &Subject, keep: bool) {
+    if from.id == into.id {
+        eprintln!("A subject can't be merged into itself.");
+        process::exit(1);
+    }
+    let (moved, moved_sessions) = prepare_transfer(conn, from, into, (None, None));
+    // Everything is merged at once, so a failure doesn't leave the subjects half merged.
+    let result: QueryResult<()> = conn.transaction(|conn| {
+        apply_transfer(conn, into, &moved, &moved_sessions)?;
+        if keep {
+            if from.archived_at.is_none() {
+                update(subjects.filter(id.eq(from.id)))
+                    .set(archived_at.eq(Some(today())))
+                    .execute(conn)?;
+            }
+            return Ok(());
+        }
+        update(exams::table.filter(exams::subject_id.eq(from.id)))
+            .set(exams::subject_id.eq(into.id))
+            .execute(conn)?;
+        update(assessments::table.filter(assessments::subject_id.eq(from.id)))
+            .set(assessments::subject_id.eq(into.id))
+            .execute(conn)?;
+        update(pomodoros::table.filter(pomodoros::subject_id.eq(from.id)))
+            .set(pomodoros::subject_id.eq(into.id))
+            .execute(conn)?;
+        update(subject_aliases::table.filter(subject_aliases::subject_id.eq(from.id)))
+            .set(subject_aliases::subject_id.eq(into.id))
+            .execute(conn)?;
+        delete(goals::table.filter(goals::subject_id.eq(from.id))).execute(conn)?;
+        // If the subject merged into was a child of the merged one, it takes its place.
+        update(
+            subjects
+                .filter(id.eq(into.id))
+                .filter(parent_id.eq(from.id)),
+        )
+        .set(parent_id.eq(from.parent_id))
+        .execute(conn)?;
+        update(subjects.filter(parent_id.eq(from.id)))
+            .set(parent_id.eq(into.id))
+            .execute(conn)?;
+        delete(subjects.filter(id.eq(from.id))).execute(conn)?;
+        Ok(())
+    });
+    match result {
+        Ok(_) => {
+            print_moved(from, into, &moved);
+            match keep {
+                true if from.archived_at.is_none() => {
+                    println!("{} archived since {}", from.name, today().format(FORMAT))
+                }
+                true => {}
+                false => println!("{} merged into {} succesfully", from.name, into.name),
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to merge the subjects: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Moves the entries of a date range from a subject to another one (e.g.: to split a subject).
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `from` - Subject whose entries are moved.
+/// * `to` - Subject the entries are moved to.
+/// * `interval` - Dates of the entries to move (first and last day, both included), where None means unbounded.
+pub fn move_entries(
+    conn: &mut SqliteConnection,
+    from: &Subject,
+    to: &Subject,
+    interval: (Option<NaiveDate>, Option<NaiveDate>),
+) {
+    if from.id == to.id {
+        eprintln!("The entries must be moved to a different subject.");
+        process::exit(1);
+    }
+    let (moved, moved_sessions) = prepare_transfer(conn, from, to, interval);
+    match conn.transaction(|conn| apply_transfer(conn, to, &moved, &moved_sessions)) {
+        Ok(_) => print_moved(from, to, &moved),
+        Err(e) => {
+            eprintln!("Failed to move the entries: {e}");
+            process::exit(1);
+        }
+    }
+}
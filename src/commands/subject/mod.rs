@@ -1,29 +1,42 @@
 //! Handles commands related to subjects.
 
 mod add;
+mod alias;
+mod archive;
+mod assessment;
+mod course;
+mod exam;
+mod grade;
 mod list;
 mod mark;
+mod matching;
+mod merge;
 mod modify;
 mod remove;
 mod usage;
 
 use crate::commands::plan::get_plan_arg;
+use crate::commands::subject::matching::{exit_with_match_error, find_subject, SubjectMatch};
+pub(crate) use crate::commands::subject::remove::delete_subjects;
 use crate::commands::subject::usage::display_bad_usage;
-use crate::debug_println;
-use crate::interpreter::{detect_unknown_arg, get_specific_arg, request_confirmation};
-use crate::models::Subject;
+use crate::interpreter::{detect_unknown_arg, get_specific_arg, parse_date, request_confirmation};
+use crate::models::{GradingScale, Period, Subject};
 use crate::schema::subjects::dsl::subjects;
 use crate::schema::subjects::{id, short_name};
+use crate::{debug_println, today};
+use colored::Color;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
 use diesel::QueryDsl;
 use diesel::{ExpressionMethods, RunQueryDsl, SqliteConnection};
 use std::process;
 
 /// It interprets a Subject argument. If the argument is a number, it will fetch it by id. If it is not a number, it will
-/// fetch it by short name, using the provided plan_id.
+/// match it against the short names, aliases and names of the subjects of the provided plan (see [find_subject]).
+/// If nothing matches there, a subject of another plan with that exact short name is fetched.
 /// # Arguments
 /// * `subject_arg` - Subject argument
 /// * `conn` - Database connection
-/// * `plan_id` - Plan id in order to fetch by short name
+/// * `plan_id` - Plan id in order to fetch by short name. If None, every subject is searched.
 pub fn get_subject(
     subject_arg: &String,
     conn: &mut SqliteConnection,
@@ -38,31 +51,145 @@ pub fn get_subject(
             }
         },
         Err(_) => {
-            match subjects
-                .filter(short_name.eq(subject_arg))
-                .load::<Subject>(conn)
-            {
-                Ok(s) => {
-                    if s.len() > 1 && plan_id.is_some() {
-                        debug_println!("There is more than one subject with same short name.");
-                        s.iter().find(|s| s.period_id == plan_id.unwrap()).cloned()
-                    } else {
-                        s.first().cloned()
+            let list = match plan_id {
+                Some(p) => match Period::from_id(conn, p) {
+                    Some(period) => period.fetch_subjects(conn),
+                    None => Vec::new(),
+                },
+                None => Subject::fetch_all(conn),
+            };
+            match find_subject(conn, subject_arg, list) {
+                SubjectMatch::Found(subj) => Some(subj),
+                _ if plan_id.is_some() => {
+                    match subjects
+                        .filter(short_name.eq(subject_arg))
+                        .load::<Subject>(conn)
+                    {
+                        Ok(s) => s.first().cloned(),
+                        Err(e) => {
+                            debug_println!(
+                                "Failed to find subject with short name '{}': {:?}",
+                                subject_arg,
+                                e
+                            );
+                            None
+                        }
                     }
                 }
-                Err(e) => {
-                    debug_println!(
-                        "Failed to find subject with short name '{}': {:?}",
-                        subject_arg,
-                        e
-                    );
-                    None
-                }
+                _ => None,
             }
         }
     }
 }
 
+/// Prints why a subject argument couldn't be resolved (with suggestions, if there are close matches) and exits.
+/// # Arguments
+/// * `subject_arg` - Subject argument.
+/// * `conn` - Database connection.
+/// * `plan_id` - Plan the subject was searched in. If None, every subject was searched.
+pub fn exit_no_subject(
+    subject_arg: &String,
+    conn: &mut SqliteConnection,
+    plan_id: Option<i32>,
+) -> ! {
+    if subject_arg.parse::<i32>().is_ok() {
+        eprintln!("There is no subject with id {subject_arg}");
+        process::exit(1);
+    }
+    let list = match plan_id.and_then(|p| Period::from_id(conn, p)) {
+        Some(period) => period.fetch_subjects(conn),
+        None => Subject::fetch_all(conn),
+    };
+    let result = find_subject(conn, subject_arg, list);
+    exit_with_match_error(subject_arg, result);
+}
+
+/// Gets a subject of the given plan from a subject argument, exiting if there isn't any.
+/// # Arguments
+/// * `subject_arg` - Subject argument.
+/// * `conn` - Database connection.
+/// * `plan_id` - Id of the plan of the subject.
+pub fn get_plan_subject(
+    subject_arg: &String,
+    conn: &mut SqliteConnection,
+    plan_id: i32,
+) -> Subject {
+    match get_subject(subject_arg, conn, Some(plan_id)) {
+        Some(subj) if subj.period_id == plan_id => subj,
+        Some(subj) => {
+            eprintln!(
+                "{} ({}) isn't a subject of the period",
+                subj.name, subj.short_name
+            );
+            process::exit(1);
+        }
+        None => exit_no_subject(subject_arg, conn, Some(plan_id)),
+    }
+}
+
+/// Gets a subject of any of the plans ocurring on a date from a subject argument, exiting if there isn't any or if
+/// the argument is ambiguous (it matches subjects of more than one active plan).
+/// # Arguments
+/// * `subject_arg` - Subject argument (id, short name, alias, name or prefix of any of them).
+/// * `conn` - Database connection.
+/// * `date` - Date the plans must be ocurring on.
+pub fn get_active_subject(
+    subject_arg: &String,
+    conn: &mut SqliteConnection,
+    date: NaiveDate,
+) -> Subject {
+    let active = Period::fetch_active(conn, &date);
+    if active.is_empty() {
+        eprintln!("There is no study plan ocurring on the current/specified date.");
+        process::exit(1);
+    }
+    if subject_arg.parse::<i32>().is_ok() {
+        return match get_subject(subject_arg, conn, None) {
+            Some(subj) => subj,
+            None => exit_no_subject(subject_arg, conn, None),
+        };
+    }
+    let mut list: Vec<Subject> = Vec::new();
+    for period in &active {
+        list.extend(period.fetch_subjects(conn));
+    }
+    let mut candidates = match find_subject(conn, subject_arg, list) {
+        SubjectMatch::Found(subj) => return subj,
+        SubjectMatch::Ambiguous(found) => found,
+        not_found => exit_with_match_error(subject_arg, not_found),
+    };
+    // A subject archived on the date can't be meant if another one that matches is still taken.
+    if candidates.iter().any(|s| !s.is_archived(date)) {
+        candidates.retain(|s| !s.is_archived(date));
+    }
+    if candidates.len() == 1 {
+        return candidates.pop().unwrap();
+    }
+    eprintln!("'{subject_arg}' is ambiguous. Use the id of the subject instead:");
+    for subj in candidates {
+        let period = active.iter().find(|p| p.id == subj.period_id).unwrap();
+        eprintln!(
+            "\t * {} (ID:{}) from {}",
+            subj.name, subj.id, period.description
+        );
+    }
+    process::exit(1);
+}
+
+/// Gets the grading scale of a plan, exiting if the plan doesn't exist.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `plan_id` - Id of the plan.
+fn get_plan_scale(conn: &mut SqliteConnection, plan_id: i32) -> GradingScale {
+    match Period::from_id(conn, plan_id) {
+        Some(period) => period.scale(),
+        None => {
+            eprintln!("There is no period with the provided id");
+            process::exit(1);
+        }
+    }
+}
+
 /// Interprets subject subcommands.
 /// # Arguments
 /// * `args` - Remaining program arguments.
@@ -93,17 +220,26 @@ pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
                     display_bad_usage();
                     process::exit(1);
                 }
-                if let Some(o) = detect_unknown_arg(args, &vec!["--name", "--short-name"], "--") {
+                if let Some(o) = detect_unknown_arg(
+                    args,
+                    &vec![
+                        "--name",
+                        "--short-name",
+                        "--credits",
+                        "--passed",
+                        "--category",
+                        "--parent",
+                        "--color",
+                    ],
+                    "--",
+                ) {
                     eprintln!("Unknown argument: {o}");
                     display_bad_usage();
                     process::exit(1);
                 }
                 let subj = match get_subject(args.first().unwrap(), conn, Some(plan_id)) {
                     Some(subj) => subj,
-                    None => {
-                        eprintln!("Failed to get subject. Does this subject exist?");
-                        process::exit(1);
-                    }
+                    None => exit_no_subject(args.first().unwrap(), conn, Some(plan_id)),
                 };
                 let new_short_name = match get_specific_arg(args, "--short-name") {
                     Some(short) => short,
@@ -113,7 +249,60 @@ pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
                     Some(n) => n,
                     None => subj.name.clone(),
                 };
-                modify::modify(conn, subj, new_short_name, new_name);
+                let new_credits = match get_specific_arg(args, "--credits") {
+                    Some(c) if c.trim().to_lowercase() == "none" => None,
+                    Some(c) => {
+                        match c.parse::<f32>() {
+                            Ok(c) if c >= 0.0 => Some(c),
+                            _ => {
+                                eprintln!("Credits must be a positive decimal or integer number (e.g.: 6)");
+                                process::exit(1);
+                            }
+                        }
+                    }
+                    None => subj.credits,
+                };
+                let new_passed = match get_specific_arg(args, "--passed") {
+                    Some(p) => match p.trim().to_lowercase().as_str() {
+                        "yes" | "y" | "true" => Some(true),
+                        "no" | "n" | "false" => Some(false),
+                        "auto" => None,
+                        _ => {
+                            eprintln!("The pass state must be 'yes', 'no' or 'auto' (determined by the mark)");
+                            process::exit(1);
+                        }
+                    },
+                    None => subj.passed,
+                };
+                let new_category = match get_specific_arg(args, "--category") {
+                    Some(c) if c.trim().to_lowercase() == "none" => None,
+                    Some(c) => Some(c.trim().to_lowercase()),
+                    None => subj.category.clone(),
+                };
+                let new_parent = match get_specific_arg(args, "--parent") {
+                    Some(p) if p.trim().to_lowercase() == "none" => None,
+                    Some(p) => Some(get_plan_subject(&p, conn, subj.period_id).id),
+                    None => subj.parent_id,
+                };
+                let new_color = match get_specific_arg(args, "--color") {
+                    Some(c) if c.parse::<Color>().is_ok() => Some(c.trim().to_lowercase()),
+                    Some(c) => {
+                        eprintln!("Unknown color '{c}'. Use a color name (red, bright blue...) or an hexadecimal color (#ff8800).");
+                        process::exit(1);
+                    }
+                    None => subj.color.clone(),
+                };
+                modify::modify(
+                    conn,
+                    subj,
+                    new_short_name,
+                    new_name,
+                    new_credits,
+                    new_passed,
+                    new_category,
+                    new_parent,
+                    new_color,
+                );
             }
             "remove" => {
                 if args.is_empty() {
@@ -130,33 +319,178 @@ pub fn interpret(args: &mut Vec<String>, conn: &mut SqliteConnection) {
                         }
                         remove::remove(conn, subj.id);
                     }
-                    None => {
-                        eprintln!("There is no subject with such id or name.");
-                        process::exit(1);
-                    }
+                    None => exit_no_subject(args.first().unwrap(), conn, Some(plan_id)),
                 }
             }
             "list" => {
                 list::list(conn, plan_id);
             }
-            option @ "mark" | option @ "unmark" => {
+            "link" => {
                 if args.len() < 2 {
                     display_bad_usage();
                     process::exit(1);
                 }
-                let subject = match get_subject(args.first().unwrap(), conn, Some(plan_id)) {
+                let subject = get_plan_subject(&args[0], conn, plan_id);
+                let other = match get_subject(&args[1], conn, None) {
+                    Some(subj) if subj.period_id != plan_id => subj,
+                    Some(_) => {
+                        eprintln!("Linked subjects must be from different periods.");
+                        process::exit(1);
+                    }
+                    None => exit_no_subject(&args[1], conn, None),
+                };
+                course::link(conn, &subject, &other);
+            }
+            option @ "unlink" | option @ "course" => {
+                if args.is_empty() {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                let subject = get_plan_subject(args.first().unwrap(), conn, plan_id);
+                match option {
+                    "unlink" => course::unlink(conn, &subject),
+                    _ => course::show(conn, &subject),
+                }
+            }
+            option @ "archive" | option @ "unarchive" => {
+                if args.is_empty() {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                let subject = get_plan_subject(args.first().unwrap(), conn, plan_id);
+                match option {
+                    "archive" => {
+                        let since = match args.get(1) {
+                            Some(d) => parse_date(d.trim()),
+                            None => today(),
+                        };
+                        archive::archive(conn, &subject, since);
+                    }
+                    _ => archive::unarchive(conn, &subject),
+                }
+            }
+            "alias" => {
+                if args.len() < 3 {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                let subject = get_plan_subject(&args[1], conn, plan_id);
+                match args[0].trim() {
+                    "add" => alias::add(conn, &subject, args[2].trim().to_string()),
+                    "remove" => alias::remove(conn, &subject, args[2].trim()),
+                    k => {
+                        debug_println!("No valid argument. Provided: {k}");
+                        display_bad_usage();
+                        process::exit(1);
+                    }
+                }
+            }
+            "merge" => {
+                if let Some(o) = detect_unknown_arg(args, &vec!["--archive", "--confirm"], "--") {
+                    eprintln!("Unknown argument: {o}");
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                if args.len() < 2 {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                let from = get_plan_subject(&args[0], conn, plan_id);
+                let into = get_plan_subject(&args[1], conn, plan_id);
+                let keep = args.contains(&"--archive".to_string());
+                if !keep && !args.contains(&"--confirm".to_string()) {
+                    println!("{}", from);
+                    request_confirmation(&format!(
+                        "Are you sure you want to merge this subject into {} and delete it? [y/n]",
+                        into.short_name
+                    ));
+                }
+                merge::merge(conn, &from, &into, keep);
+            }
+            "move-entries" => {
+                if let Some(o) = detect_unknown_arg(args, &vec!["--from", "--to"], "--") {
+                    eprintln!("Unknown argument: {o}");
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                if args.len() < 2 {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                let from = get_plan_subject(&args[0], conn, plan_id);
+                let to = match get_subject(&args[1], conn, Some(plan_id)) {
                     Some(subj) => subj,
-                    None => {
-                        eprintln!("Failed to get subject. Does this subject exist?");
+                    None => exit_no_subject(&args[1], conn, Some(plan_id)),
+                };
+                let first = get_specific_arg(args, "--from").map(|d| parse_date(d.trim()));
+                let last = get_specific_arg(args, "--to").map(|d| parse_date(d.trim()));
+                if let (Some(f), Some(l)) = (first, last) {
+                    if f > l {
+                        eprintln!("The first date must be before the last one.");
                         process::exit(1);
                     }
+                }
+                merge::move_entries(conn, &from, &to, (first, last));
+            }
+            "exam" => {
+                exam::interpret(args, conn, plan_id);
+            }
+            "assessment" => {
+                assessment::interpret(args, conn, plan_id);
+            }
+            "grade" => {
+                if let Some(o) = detect_unknown_arg(args, &vec!["--target", "--sync"], "--") {
+                    eprintln!("Unknown argument: {o}");
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                if args.is_empty() {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                let subject = get_plan_subject(args.first().unwrap(), conn, plan_id);
+                let scale = get_plan_scale(conn, plan_id);
+                let target = match args.contains(&"--target".to_string()) {
+                    true => {
+                        match get_specific_arg(args, "--target").and_then(|t| scale.parse_score(&t))
+                        {
+                            Some(t) => t,
+                            None => {
+                                eprintln!("The target grade must be a valid score in the {} grading scale", scale);
+                                process::exit(1);
+                            }
+                        }
+                    }
+                    false => scale.pass_mark(),
+                };
+                grade::grade(
+                    conn,
+                    subject,
+                    scale,
+                    target,
+                    args.contains(&"--sync".to_string()),
+                );
+            }
+            option @ "mark" | option @ "unmark" => {
+                if args.is_empty() || (option == "mark" && args.len() < 2) {
+                    display_bad_usage();
+                    process::exit(1);
+                }
+                let subject = match get_subject(args.first().unwrap(), conn, Some(plan_id)) {
+                    Some(subj) => subj,
+                    None => exit_no_subject(args.first().unwrap(), conn, Some(plan_id)),
                 };
                 match option {
                     "mark" => {
-                        let mark = match args.get(1).unwrap().parse::<f32>() {
-                            Ok(m) => m,
-                            Err(_) => {
-                                eprintln!("Mark must be a decimal or integer number (e.g.: 5.2)");
+                        let scale = get_plan_scale(conn, subject.period_id);
+                        let mark = match scale.parse_score(args.get(1).unwrap()) {
+                            Some(m) => m,
+                            None => {
+                                eprintln!(
+                                    "Mark must be a valid score in the {} grading scale (e.g.: {})",
+                                    scale,
+                                    scale.format_score(scale.pass_mark())
+                                );
                                 process::exit(1);
                             }
                         };
@@ -1,6 +1,6 @@
 use crate::models::Subject;
 use crate::schema::subjects::dsl::subjects;
-use crate::schema::subjects::{id, name, short_name};
+use crate::schema::subjects::{category, color, credits, id, name, parent_id, passed, short_name};
 use diesel::ExpressionMethods;
 use diesel::{update, QueryDsl, RunQueryDsl, SqliteConnection};
 use std::process;
@@ -11,11 +11,22 @@ use std::process;
 /// * `subj` - Subject to modify.
 /// * `new_short_name` - New subject's short name
 /// * `new_name` - New subject's name.
+/// * `new_credits` - New subject's credits.
+/// * `new_passed` - New subject's pass/fail state, where None means it is determined by its mark.
+/// * `new_category` - New subject's category.
+/// * `new_parent` - New subject's parent subject id.
+/// * `new_color` - New subject's color.
+#[allow(clippy::too_many_arguments)]
 pub fn modify(
     conn: &mut SqliteConnection,
     subj: Subject,
     new_short_name: String,
     new_name: String,
+    new_credits: Option<f32>,
+    new_passed: Option<bool>,
+    new_category: Option<String>,
+    new_parent: Option<i32>,
+    new_color: Option<String>,
 ) {
     // Two subjects from the same plan can't have the same short name.
     if Subject::fetch_all(conn).iter().any(|s| {
@@ -24,8 +35,34 @@ pub fn modify(
         eprintln!("A subject already exists in the period with the same short name.");
         process::exit(1);
     }
+    if let Some(parent) = new_parent {
+        let period_subjects = Subject::fetch_all(conn)
+            .into_iter()
+            .filter(|s| s.period_id == subj.period_id)
+            .collect::<Vec<Subject>>();
+        match period_subjects.iter().find(|s| s.id == parent) {
+            None => {
+                eprintln!("The parent subject must be from the same period.");
+                process::exit(1);
+            }
+            // The subject can't be an ancestor of its own parent.
+            Some(p) if p.id == subj.id || is_ancestor(subj.id, p, &period_subjects) => {
+                eprintln!("A subject can't be the parent of one of its ancestors.");
+                process::exit(1);
+            }
+            Some(_) => {}
+        }
+    }
     match update(subjects.filter(id.eq(subj.id)))
-        .set((short_name.eq(new_short_name), name.eq(new_name)))
+        .set((
+            short_name.eq(new_short_name),
+            name.eq(new_name),
+            credits.eq(new_credits),
+            passed.eq(new_passed),
+            category.eq(new_category),
+            parent_id.eq(new_parent),
+            color.eq(new_color),
+        ))
         .execute(conn)
     {
         Ok(_) => {
@@ -37,3 +74,23 @@ pub fn modify(
         }
     }
 }
+
+/// Determines whether a subject is an ancestor of another one.
+/// # Arguments
+/// * `ancestor` - Id of the possible ancestor.
+/// * `subject` - Subject whose ancestors are checked.
+/// * `list` - Subjects of the period.
+fn is_ancestor(ancestor: i32, subject: &Subject, list: &[Subject]) -> bool {
+    let mut current = subject;
+    for _ in 0..list.len() {
+        match current
+            .parent_id
+            .and_then(|p| list.iter().find(|s| s.id == p))
+        {
+            Some(parent) if parent.id == ancestor => return true,
+            Some(parent) => current = parent,
+            None => return false,
+        }
+    }
+    false
+}
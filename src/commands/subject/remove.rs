@@ -1,16 +1,37 @@
 use crate::schema::subjects::dsl::subjects;
-use crate::schema::subjects::id;
+use crate::schema::subjects::{id, parent_id};
+use crate::schema::{assessments, entry, exams, goals, pomodoros, sessions, subject_aliases};
 use diesel::ExpressionMethods;
 use diesel::QueryDsl;
-use diesel::{delete, RunQueryDsl, SqliteConnection};
+use diesel::{delete, update, Connection, QueryResult, RunQueryDsl, SqliteConnection};
 use std::process;
 
+/// Deletes subjects along with their entries, sessions, pomodoros, exams, assessments, aliases and goals. Their child
+/// subjects are left without a parent. Meant to be run inside a transaction.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `ids` - Ids of the subjects.
+pub(crate) fn delete_subjects(conn: &mut SqliteConnection, ids: &[i32]) -> QueryResult<()> {
+    delete(entry::table.filter(entry::subject_id.eq_any(ids))).execute(conn)?;
+    delete(pomodoros::table.filter(pomodoros::subject_id.eq_any(ids))).execute(conn)?;
+    delete(sessions::table.filter(sessions::subject_id.eq_any(ids))).execute(conn)?;
+    delete(exams::table.filter(exams::subject_id.eq_any(ids))).execute(conn)?;
+    delete(assessments::table.filter(assessments::subject_id.eq_any(ids))).execute(conn)?;
+    delete(subject_aliases::table.filter(subject_aliases::subject_id.eq_any(ids))).execute(conn)?;
+    delete(goals::table.filter(goals::subject_id.eq_any(ids))).execute(conn)?;
+    update(subjects.filter(parent_id.eq_any(ids)))
+        .set(parent_id.eq(None::<i32>))
+        .execute(conn)?;
+    delete(subjects.filter(id.eq_any(ids))).execute(conn)?;
+    Ok(())
+}
+
 /// Removes an existing subject.
 /// # Arguments
 /// * `conn` - Database connection.
 /// * `subj_id` - Subject's id.
 pub fn remove(conn: &mut SqliteConnection, subj_id: i32) {
-    match delete(subjects.filter(id.eq(subj_id))).execute(conn) {
+    match conn.transaction(|conn| delete_subjects(conn, &[subj_id])) {
         Ok(_) => {
             println!("Subject removed succesfully");
         }
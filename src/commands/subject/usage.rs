@@ -4,11 +4,27 @@
 pub fn display_bad_usage() {
     println!(
         "Bad usage: {} subject ...:\n
+        Subjects can be given by id, short name, alias, name (ignoring case) or the beginning of any of them.\n
         - add [--plan (plan id)] (short name) (name): Adds a new subject to the current/specified plan.
-        - modify (id/short name) [--name (new name)] [--short-name (new short name)]: Modifies a subject.
+        - modify (id/short name) [--name (new name)] [--short-name (new short name)] [--credits (credits)] [--passed (yes/no/auto)] [--category (category/none)] [--parent (id/short name/none)] [--color (color name/#rrggbb)]: Modifies a subject. Subjects without category take the one of their parent.
         - remove (id/short name) [--confirm] : Removes a subject.
         - list [--plan (plan id)]: Lists all the subjects from the current/specified period.
+        - link (id/short name) (other subject id) [--plan (plan id)]: Links a subject of another period as the same course (e.g.: a year-long subject).
+        - unlink (id/short name) [--plan (plan id)]: Removes a subject from its course.
+        - course (id/short name) [--plan (plan id)]: Shows the time, goals and marks of the course of a subject, period by period and in total.
+        - alias add/remove (id/short name) (alias) [--plan (plan id)]: Adds or removes another name the subject can be referred to by.
+        - archive (id/short name) [date] [--plan (plan id)]: Archives a subject (e.g.: a dropped one) from today/the given date on. It is hidden from the status and no time can be logged to it, but it is kept in reports and exports.
+        - unarchive (id/short name) [--plan (plan id)]: Unarchives a subject.
+        - merge (from id/short name) (into id/short name) [--archive] [--confirm] [--plan (plan id)]: Moves every entry of a subject into another one, adding up the time of the same days, and then removes it (or archives it, with --archive). When removed, its exams, assessments and child subjects are moved too.
+        - move-entries (from id/short name) (to id/short name) [--from (date)] [--to (date)] [--plan (plan id)]: Moves the entries of a subject between two dates (both included) to another subject.
         - mark (id/short name) (mark)
-        - unmark (id/short name)\n\
+        - unmark (id/short name)
+        - exam add (id/short name) (date) (kind) [--weight (percentage)] [description]: Adds an exam or deadline to a subject.
+        - exam list [id/short name]: Lists the exams of a subject, or of every subject from the current/specified period.
+        - exam remove (exam id): Removes an exam.
+        - assessment add (id/short name) (weight) (name) [--max (max score)] [--min (min score to pass)] [--date (date)]: Adds a graded assessment to a subject.
+        - assessment score (assessment id) (score) / unscore (assessment id): Scores or unscores an assessment.
+        - assessment list [id/short name] / remove (assessment id): Lists or removes assessments.
+        - grade (id/short name) [--target (grade)] [--sync]: Computes the final grade from the assessments, and the scores needed to get the target grade. --sync updates the subject's mark.\n\
     ", crate::env::args().collect::<Vec<String>>().first().unwrap());
 }
@@ -1,5 +1,6 @@
 //! Handles the connection to the database
-use crate::{debug_println, get_data_dir};
+use crate::models::Setting;
+use crate::{debug_println, get_data_dir, set_day_start};
 use diesel::{Connection, SqliteConnection};
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 use std::{env, process};
@@ -18,14 +19,18 @@ fn get_connection_string() -> String {
     }
 }
 
-/// It stablishes an SQLite connection, runs the pending migrations and returns the connection itself.
+/// It stablishes an SQLite connection, runs the pending migrations, loads the start of the study day and returns the
+/// connection itself.
 pub fn stablish_and_run_migrations() -> SqliteConnection {
     dotenv::dotenv().ok();
     let conn = get_connection_string();
     debug_println!("connecting to {conn}");
     match SqliteConnection::establish(&conn) {
         Ok(mut conn) => match conn.run_pending_migrations(MIGRATIONS) {
-            Ok(_) => conn,
+            Ok(_) => {
+                set_day_start(Setting::day_start(&mut conn));
+                conn
+            }
             Err(e) => {
                 eprintln!("Error running migrations: {e}");
                 process::exit(1);
@@ -4,16 +4,33 @@
 //! useful functions to every command submodule.
 
 use crate::commands::entry::EntryMode;
-use crate::commands::{entry, export, plan, status, subject};
+use crate::commands::{
+    activity, analyze, chart, config, entry, export, goal, gpa, heatmap, log, plan, pomodoro,
+    program, search, status, subject,
+};
 use crate::db_connection_handler::stablish_and_run_migrations;
-use crate::{debug_println, usage, FORMAT};
-use diesel::internal::derives::multiconnection::chrono::{Local, NaiveDate};
+use crate::{debug_println, today, usage, FORMAT};
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
 use std::process;
 
 /// Interprets the first command of the arguments provided and delegates the work to submodule commands
 /// # Arguments
 /// * `args` - Program arguments.
 pub fn interpret(args: &mut Vec<String>) {
+    // Colors are disabled by NO_COLOR or when the output isn't a terminal, unless forced with --color. It is only
+    // taken before the command, since `subject modify --color` sets the color of a subject.
+    if args.first().is_some_and(|a| a == "--color") {
+        match args.get(1).map(|c| c.trim().to_lowercase()).as_deref() {
+            Some("always") => colored::control::set_override(true),
+            Some("never") => colored::control::set_override(false),
+            Some("auto") => colored::control::unset_override(),
+            _ => {
+                eprintln!("The color mode must be 'auto', 'always' or 'never'");
+                process::exit(1);
+            }
+        }
+        args.drain(0..2);
+    }
     match args.len() {
         0 => {
             usage::display_usage();
@@ -27,11 +44,23 @@ pub fn interpret(args: &mut Vec<String>) {
             match option.trim() {
                 "status" => status::display_status(&mut conn, args),
                 "plan" => plan::interpret(args, &mut conn),
+                "program" => program::interpret(args, &mut conn),
                 "subject" => subject::interpret(args, &mut conn),
                 "add" => entry::time_setter(&mut conn, args, EntryMode::Add),
                 "substract" => entry::time_setter(&mut conn, args, EntryMode::Substract),
                 "set" => entry::time_setter(&mut conn, args, EntryMode::Set),
+                "entry" => entry::interpret(args, &mut conn),
+                "log" => log::display_log(&mut conn, args),
+                "search" => search::search(&mut conn, args),
                 "export" => export::interpret(args, &mut conn),
+                "heatmap" => heatmap::display_heatmap(&mut conn, args),
+                "chart" => chart::display_charts(&mut conn, args),
+                "goal" => goal::interpret(args, &mut conn),
+                "gpa" => gpa::display_gpa(&mut conn, args),
+                "analyze" => analyze::interpret(args, &mut conn),
+                "activity" => activity::interpret(args, &mut conn),
+                "pomodoro" => pomodoro::interpret(args, &mut conn),
+                "config" => config::interpret(args, &mut conn),
                 _ => {
                     usage::display_usage();
                     process::exit(1);
@@ -52,6 +81,18 @@ pub fn get_specific_arg(args: &mut [String], find: &str) -> Option<String> {
     }
 }
 
+/// It searchs the value of the argument provided and removes both the argument and its value from the arguments,
+/// so that the remaining arguments can be read by position.
+/// # Arguments
+/// * `args` - program arguments
+/// * `find` - argument flag to find
+pub fn take_specific_arg(args: &mut Vec<String>, find: &str) -> Option<String> {
+    let index = args.iter().position(|a| a == find)?;
+    let value = args.get(index + 1).cloned();
+    args.drain(index..(index + 2).min(args.len()));
+    value
+}
+
 /// Prints the given string and waits for user input. If something different to 'y' is entered, it will end the program
 /// with code 0.
 /// # Arguments
@@ -92,15 +133,40 @@ pub fn detect_unknown_arg(args: &Vec<String>, options: &Vec<&str>, prefix: &str)
     None
 }
 
+/// It parses a duration given in minutes (`90`), hours (`8h`) or both (`1h30m`, `1h 30min`), returning the amount of
+/// minutes. Returns None if the duration can't be parsed.
+/// # Arguments
+/// * `duration` - Duration to parse.
+pub fn parse_duration(duration: &str) -> Option<i32> {
+    if let Ok(minutes) = duration.trim().parse::<i32>() {
+        return if minutes >= 0 { Some(minutes) } else { None };
+    }
+    let duration = duration.to_lowercase().replace("min", "m").replace(' ', "");
+    let mut total: i32 = 0;
+    let mut number = String::new();
+    for c in duration.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'h' | 'm' => {
+                let n = number.parse::<i32>().ok()?;
+                let n = if c == 'h' { n.checked_mul(60)? } else { n };
+                total = total.checked_add(n)?;
+                number.clear();
+            }
+            _ => return None,
+        }
+    }
+    match number.is_empty() && !duration.is_empty() {
+        true => Some(total),
+        false => None,
+    }
+}
+
 pub fn parse_date(date: &str) -> NaiveDate {
     match NaiveDate::parse_from_str(date, FORMAT) {
         Ok(d) => d,
         Err(e) => match date.to_lowercase().trim() {
-            "@yest" | "@yesterday" => Local::now()
-                .naive_local()
-                .date()
-                .pred_opt()
-                .expect("Unexpected date provided"),
+            "@yest" | "@yesterday" => today().pred_opt().expect("Unexpected date provided"),
             _ => {
                 eprintln!("Failed to parse date. Remember using format '{}'", FORMAT);
                 debug_println!("{e}");
@@ -9,11 +9,18 @@ mod models;
 mod schema;
 mod usage;
 
+use diesel::internal::derives::multiconnection::chrono::{
+    Local, NaiveDate, NaiveDateTime, NaiveTime,
+};
 use std::path::Path;
+use std::sync::OnceLock;
 use std::{env, fs};
+use terminal_size::{terminal_size, Width};
 
 /// Date format for [NaiveDate::parse_from_str][diesel::internal::derives::multiconnection::chrono::NaiveDate::parse_from_str] method
 pub const FORMAT: &str = "%d-%m-%Y";
+/// Time of the day when a study day starts, loaded from the settings when the database is opened.
+static DAY_START: OnceLock<NaiveTime> = OnceLock::new();
 /// It prints a formatted message (just like println! would), with '\[DEBUG]' prefix and colored in yellow.
 #[macro_export]
 macro_rules! debug_println {
@@ -36,6 +43,50 @@ pub fn format_hours_and_minutes(time: i32) -> String {
     }
 }
 
+/// It sets the time of the day when a study day starts. It can only be set once.
+/// # Arguments
+/// * `time` - Start of the study day.
+pub fn set_day_start(time: NaiveTime) {
+    let _ = DAY_START.set(time);
+}
+
+/// It gets the time of the day when a study day starts (midnight by default).
+pub fn day_start() -> NaiveTime {
+    *DAY_START.get().unwrap_or(&NaiveTime::MIN)
+}
+
+/// It gets the study day a moment belongs to: time before the start of the day (e.g.: at 01:00, when days start at
+/// 04:00) belongs to the previous day.
+/// # Arguments
+/// * `moment` - Date and time.
+pub fn study_day(moment: NaiveDateTime) -> NaiveDate {
+    (moment - (day_start() - NaiveTime::MIN)).date()
+}
+
+/// It gets the moment a time of a study day refers to, which is on the next date if it is before the start of the day.
+/// # Arguments
+/// * `day` - Study day.
+/// * `time` - Time of the day.
+pub fn moment_of(day: NaiveDate, time: NaiveTime) -> NaiveDateTime {
+    match time < day_start() {
+        true => day.succ_opt().unwrap().and_time(time),
+        false => day.and_time(time),
+    }
+}
+
+/// It gets the current study day.
+pub fn today() -> NaiveDate {
+    study_day(Local::now().naive_local())
+}
+
+/// It gets the width of the terminal, in columns. If it can't be determined, 80 columns are assumed.
+pub fn get_terminal_width() -> usize {
+    match terminal_size() {
+        Some((Width(w), _)) => w as usize,
+        None => 80,
+    }
+}
+
 /// It gets a String with the path of the program data folder. If it doesn't exist, then it's created.
 /// Note that depending on the OS the data dir will be different.
 /// * If the OS is Windows, the folder will be `%APPDATA%\.student_datahub`
@@ -67,3 +118,33 @@ fn main() {
     debug_println!("args: {:?}", args);
     interpreter::interpret(&mut args);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moment(m: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(m, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    // The start of the day can only be set once, so it is tested in a single test.
+    #[test]
+    fn study_day_moves_the_night_to_the_previous_day() {
+        set_day_start(NaiveTime::from_hms_opt(4, 0, 0).unwrap());
+        let day = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        assert_eq!(study_day(moment("2026-10-20 01:30")), day);
+        assert_eq!(study_day(moment("2026-10-19 23:00")), day);
+        assert_eq!(
+            study_day(moment("2026-10-20 04:00")),
+            day.succ_opt().unwrap()
+        );
+        assert_eq!(
+            moment_of(day, NaiveTime::from_hms_opt(1, 30, 0).unwrap()),
+            moment("2026-10-20 01:30")
+        );
+        assert_eq!(
+            moment_of(day, NaiveTime::from_hms_opt(21, 0, 0).unwrap()),
+            moment("2026-10-19 21:00")
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use crate::models::ActivityType;
+use crate::schema::activity_types::dsl::activity_types;
+use crate::schema::activity_types::{id, name};
+use crate::schema::entry::dsl::entry;
+use crate::schema::entry::{activity, dedicated_time};
+use diesel::dsl::sum;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::fmt::{Display, Formatter};
+use std::process;
+
+/// Activity type of the entries when none is given.
+pub const DEFAULT_ACTIVITY: &str = "study";
+
+impl Display for ActivityType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl ActivityType {
+    /// Fetches all activity types, sorted by id.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    pub fn fetch_all(conn: &mut SqliteConnection) -> Vec<ActivityType> {
+        match activity_types.order_by(id).load::<ActivityType>(conn) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the activity types: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets an activity type given its name (ignoring case). Returns None if there isn't any.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `name_to_fetch` - Name of the activity type.
+    pub fn from_name(conn: &mut SqliteConnection, name_to_fetch: &str) -> Option<ActivityType> {
+        match activity_types
+            .filter(name.eq(name_to_fetch.trim().to_lowercase()))
+            .load::<ActivityType>(conn)
+        {
+            Ok(v) => v.first().cloned(),
+            Err(e) => {
+                eprintln!("Failed to fetch the activity type: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the time logged to the activity type, in minutes.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    pub fn total_time(&self, conn: &mut SqliteConnection) -> i32 {
+        match entry
+            .select(sum(dedicated_time))
+            .filter(activity.eq(&self.name))
+            .first::<Option<i64>>(conn)
+        {
+            Ok(t) => t.unwrap_or(0) as i32,
+            Err(e) => {
+                eprintln!("Failed to fetch the time of the activity type: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the number of entries of the activity type.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    pub fn count_entries(&self, conn: &mut SqliteConnection) -> i64 {
+        match entry
+            .filter(activity.eq(&self.name))
+            .count()
+            .get_result::<i64>(conn)
+        {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("Failed to fetch the entries of the activity type: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
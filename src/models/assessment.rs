@@ -0,0 +1,73 @@
+use crate::models::Assessment;
+use crate::schema::assessments::dsl::assessments;
+use crate::schema::assessments::{id, subject_id};
+use crate::FORMAT;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::fmt::{Display, Formatter};
+use std::process;
+
+impl Display for Assessment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.score {
+            Some(score) => write!(f, "{}: {}/{}", self.name, score, self.max_score)?,
+            None => write!(f, "{}: -/{}", self.name, self.max_score)?,
+        }
+        write!(f, " (weight {}", self.weight)?;
+        if let Some(min_score) = self.min_score {
+            write!(f, ", min {}", min_score)?;
+        }
+        if let Some(date) = self.date {
+            write!(f, ", {}", date.format(FORMAT))?;
+        }
+        write!(f, ") (ID:{})", self.id)
+    }
+}
+
+impl Assessment {
+    /// Fetches all assessments of a subject.
+    /// # Arguments
+    /// * `conn` - Database connection
+    /// * `subject` - Id of the subject.
+    pub fn fetch_by_subject(conn: &mut SqliteConnection, subject: i32) -> Vec<Assessment> {
+        match assessments
+            .filter(subject_id.eq(subject))
+            .order_by(id)
+            .load::<Assessment>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the assessments: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the assessment given its id. Returns None if there isn't any.
+    /// # Arguments
+    /// * `conn` - Database connection
+    /// * `id_to_fetch` - Assessment id.
+    pub fn from_id(conn: &mut SqliteConnection, id_to_fetch: i32) -> Option<Assessment> {
+        match assessments
+            .filter(id.eq(id_to_fetch))
+            .load::<Assessment>(conn)
+        {
+            Ok(a) => a.first().cloned(),
+            Err(e) => {
+                eprintln!("Failed to fetch assessment: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the score of the assessment relative to its maximum score (from 0 to 1), if it is scored.
+    pub fn relative_score(&self) -> Option<f32> {
+        self.score.map(|s| s / self.max_score)
+    }
+
+    /// Determines whether the assessment is scored below its minimum score.
+    pub fn below_minimum(&self) -> bool {
+        matches!((self.score, self.min_score), (Some(s), Some(m)) if s < m)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::models::Break;
+use crate::schema::breaks::dsl::breaks;
+use crate::schema::breaks::{period_id, start_date};
+use crate::FORMAT;
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, TimeDelta};
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::fmt::{Display, Formatter};
+use std::process;
+
+impl Display for Break {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} - {}\t{} (ID:{})",
+            self.start_date.format(FORMAT),
+            self.end_date.format(FORMAT),
+            self.label,
+            self.id
+        )
+    }
+}
+
+impl Break {
+    /// Fetches all breaks of a period, sorted by start date.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `period` - Id of the period.
+    pub fn fetch_by_period(conn: &mut SqliteConnection, period: i32) -> Vec<Break> {
+        match breaks
+            .filter(period_id.eq(period))
+            .order_by(start_date)
+            .load::<Break>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the breaks: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Determines whether a day is inside the break.
+    /// # Arguments
+    /// * `day` - Day to check.
+    pub fn contains(&self, day: NaiveDate) -> bool {
+        day >= self.start_date && day <= self.end_date
+    }
+
+    /// Gets the label of the breaks that cover a whole interval. Returns None if any day of the interval is a
+    /// teaching day.
+    /// # Arguments
+    /// * `list` - Breaks of the period.
+    /// * `interval` - Date interval (start, end).
+    pub fn covering_label(list: &[Break], interval: (NaiveDate, NaiveDate)) -> Option<String> {
+        let mut labels: Vec<&str> = Vec::new();
+        let mut day = interval.0;
+        while day <= interval.1 {
+            let b = list.iter().find(|b| b.contains(day))?;
+            if !labels.contains(&b.label.as_str()) {
+                labels.push(&b.label);
+            }
+            day += TimeDelta::days(1);
+        }
+        match labels.is_empty() {
+            true => None,
+            false => Some(labels.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(d, FORMAT).unwrap()
+    }
+
+    fn calendar() -> Vec<Break> {
+        vec![
+            Break {
+                id: 1,
+                period_id: 1,
+                start_date: day("12-10-2026"),
+                end_date: day("18-10-2026"),
+                label: String::from("Autumn break"),
+            },
+            Break {
+                id: 2,
+                period_id: 1,
+                start_date: day("19-10-2026"),
+                end_date: day("20-10-2026"),
+                label: String::from("Holiday"),
+            },
+        ]
+    }
+
+    #[test]
+    fn covering_label_of_an_interval_inside_a_break() {
+        assert_eq!(
+            Break::covering_label(&calendar(), (day("12-10-2026"), day("18-10-2026"))),
+            Some(String::from("Autumn break"))
+        );
+    }
+
+    #[test]
+    fn covering_label_joins_consecutive_breaks() {
+        assert_eq!(
+            Break::covering_label(&calendar(), (day("17-10-2026"), day("20-10-2026"))),
+            Some(String::from("Autumn break, Holiday"))
+        );
+    }
+
+    #[test]
+    fn covering_label_is_none_with_a_teaching_day() {
+        assert_eq!(
+            Break::covering_label(&calendar(), (day("11-10-2026"), day("18-10-2026"))),
+            None
+        );
+        assert_eq!(
+            Break::covering_label(&calendar(), (day("20-10-2026"), day("21-10-2026"))),
+            None
+        );
+    }
+}
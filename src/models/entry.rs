@@ -1,21 +1,85 @@
 use crate::models::{Entry, Period};
-use crate::schema::entry::date;
 use crate::schema::entry::dsl::entry;
+use crate::schema::entry::{date, id, subject_id};
 use crate::schema::periods::dsl::periods;
-use crate::schema::periods::{final_date, initial_date};
+use crate::schema::subjects::dsl::subjects;
 use diesel::internal::derives::multiconnection::chrono::NaiveDate;
 use diesel::ExpressionMethods;
-use diesel::QueryDsl;
+use diesel::{QueryDsl, SelectableHelper};
 use diesel::{RunQueryDsl, SqliteConnection};
 
+/// Highest focus rating (the lowest one is 1).
+pub const MAX_FOCUS: f64 = 5.0;
+
 impl Entry {
-    /// Gets the period to which the entry belongs. If it doesn't belong to any period (it should), returns `None`.
+    /// Parses a focus rating, which must be a number from 1 to 5. Returns None if it isn't valid.
+    /// # Arguments
+    /// * `rating` - Focus rating to parse.
+    pub fn parse_focus(rating: &str) -> Option<f64> {
+        rating
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|f| (1.0..=MAX_FOCUS).contains(f))
+    }
+
+    /// Gets the effective minutes of the entry: its time weighted by its focus, so an hour with focus 5 counts as an
+    /// hour and an hour with focus 3 as 36 minutes. Time without a rating isn't weighted.
+    pub fn effective_minutes(&self) -> f64 {
+        match self.focus {
+            Some(f) => self.dedicated_time as f64 * f / MAX_FOCUS,
+            None => self.dedicated_time as f64,
+        }
+    }
+
+    /// Gets the average focus of some entries, weighted by their time. Returns None if none of them is rated.
+    /// # Arguments
+    /// * `entries` - Entries to average.
+    pub fn average_focus<'a>(entries: impl IntoIterator<Item = &'a Entry>) -> Option<f64> {
+        let (time, weighted) = entries
+            .into_iter()
+            .filter_map(|e| e.focus.map(|f| (e.dedicated_time as f64, f)))
+            .fold((0.0, 0.0), |(t, w), (time, f)| (t + time, w + time * f));
+        match time > 0.0 {
+            true => Some(weighted / time),
+            false => None,
+        }
+    }
+
+    /// Normalizes a tag: it is lowercased and the leading '#' is removed. Returns None if the tag is empty or has
+    /// commas or spaces.
+    /// # Arguments
+    /// * `tag` - Tag to normalize.
+    pub fn normalize_tag(tag: &str) -> Option<String> {
+        let tag = tag.trim().trim_start_matches('#').to_lowercase();
+        if tag.is_empty() || tag.contains(',') || tag.contains(char::is_whitespace) {
+            None
+        } else {
+            Some(tag)
+        }
+    }
+
+    /// Gets the tags of the entry.
+    pub fn tag_list(&self) -> Vec<String> {
+        match &self.tags {
+            Some(t) => t
+                .split(',')
+                .filter(|t| !t.is_empty())
+                .map(String::from)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Gets the period to which the entry belongs (the period of its subject). If it doesn't belong to any period
+    /// (it should), returns `None`.
     /// # Arguments
     /// * conn - Database connection
     pub fn get_period(&self, conn: &mut SqliteConnection) -> Option<Period> {
         periods
-            .filter(initial_date.le(&self.date))
-            .filter(final_date.ge(&self.date))
+            .inner_join(subjects)
+            .filter(crate::schema::subjects::id.eq(self.subject_id))
+            .select(Period::as_select())
             .load::<Period>(conn)
             .expect("Error loading period")
             .pop()
@@ -57,4 +121,73 @@ impl Entry {
             (None, None) => entry.load::<Entry>(conn).expect("Error loading entry"),
         }
     }
+
+    /// Gets the entry given its id. Returns None if there isn't any.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `id_to_fetch` - Entry id.
+    pub fn from_id(conn: &mut SqliteConnection, id_to_fetch: i32) -> Option<Entry> {
+        entry
+            .filter(id.eq(id_to_fetch))
+            .load::<Entry>(conn)
+            .expect("Error loading entry")
+            .pop()
+    }
+
+    /// Fetches the entries of some subjects in a given date interval, sorted by date and id.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `subject_ids` - Ids of the subjects.
+    /// * `interval` - Date interval (start, end), where None means unbounded.
+    pub fn fetch_by_subjects(
+        conn: &mut SqliteConnection,
+        subject_ids: &[i32],
+        interval: (Option<NaiveDate>, Option<NaiveDate>),
+    ) -> Vec<Entry> {
+        let mut query = entry.filter(subject_id.eq_any(subject_ids)).into_boxed();
+        if let Some(s) = interval.0 {
+            query = query.filter(date.ge(s));
+        }
+        if let Some(e) = interval.1 {
+            query = query.filter(date.le(e));
+        }
+        query
+            .order_by((date, id))
+            .load::<Entry>(conn)
+            .expect("Error loading entry")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_focus_accepts_ratings_from_one_to_five() {
+        assert_eq!(Entry::parse_focus("1"), Some(1.0));
+        assert_eq!(Entry::parse_focus(" 4.5 "), Some(4.5));
+        assert_eq!(Entry::parse_focus("5"), Some(5.0));
+    }
+
+    #[test]
+    fn parse_focus_rejects_invalid_ratings() {
+        assert_eq!(Entry::parse_focus("0"), None);
+        assert_eq!(Entry::parse_focus("5.5"), None);
+        assert_eq!(Entry::parse_focus("-3"), None);
+        assert_eq!(Entry::parse_focus("good"), None);
+    }
+
+    #[test]
+    fn normalize_tag_lowercases_and_removes_the_hash() {
+        assert_eq!(Entry::normalize_tag("#Exam"), Some(String::from("exam")));
+        assert_eq!(Entry::normalize_tag(" prep "), Some(String::from("prep")));
+    }
+
+    #[test]
+    fn normalize_tag_rejects_empty_tags_and_separators() {
+        assert_eq!(Entry::normalize_tag(""), None);
+        assert_eq!(Entry::normalize_tag("#"), None);
+        assert_eq!(Entry::normalize_tag("exam prep"), None);
+        assert_eq!(Entry::normalize_tag("exam,prep"), None);
+    }
 }
@@ -0,0 +1,94 @@
+use crate::models::{Exam, Period, Subject};
+use crate::schema::exams::dsl::exams;
+use crate::schema::exams::{date, subject_id};
+use crate::schema::subjects::dsl::subjects;
+use crate::schema::subjects::period_id;
+use crate::FORMAT;
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::fmt::{Display, Formatter};
+use std::process;
+
+impl Display for Exam {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.date.format(FORMAT), self.kind)?;
+        if let Some(weight) = self.weight {
+            write!(f, " ({}%)", weight)?;
+        }
+        if let Some(description) = &self.description {
+            write!(f, ": {}", description)?;
+        }
+        write!(f, " (ID:{})", self.id)
+    }
+}
+
+impl Exam {
+    /// Fetches all exams of a subject, sorted by date.
+    /// # Arguments
+    /// * `conn` - Database connection
+    /// * `subject` - Id of the subject.
+    pub fn fetch_by_subject(conn: &mut SqliteConnection, subject: i32) -> Vec<Exam> {
+        match exams
+            .filter(subject_id.eq(subject))
+            .order_by(date)
+            .load::<Exam>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the exams: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Fetches the exams of a period from a date on, sorted by date, along with their subject.
+    /// # Arguments
+    /// * `conn` - Database connection
+    /// * `period` - Period of the exams.
+    /// * `from` - First date to fetch.
+    pub fn fetch_upcoming(
+        conn: &mut SqliteConnection,
+        period: &Period,
+        from: NaiveDate,
+    ) -> Vec<(Exam, Subject)> {
+        match exams
+            .inner_join(subjects)
+            .filter(period_id.eq(period.id))
+            .filter(date.ge(from))
+            .order_by(date)
+            .load::<(Exam, Subject)>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the exams: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the date of the latest exam of the same subject before this one and before a given date. Returns None if
+    /// there isn't any.
+    /// # Arguments
+    /// * `conn` - Database connection
+    /// * `before` - Date the milestone must be strictly before.
+    pub fn previous_milestone(
+        &self,
+        conn: &mut SqliteConnection,
+        before: NaiveDate,
+    ) -> Option<NaiveDate> {
+        match exams
+            .select(diesel::dsl::max(date))
+            .filter(subject_id.eq(self.subject_id))
+            .filter(date.lt(self.date.min(before)))
+            .first::<Option<NaiveDate>>(conn)
+        {
+            Ok(d) => d,
+            Err(e) => {
+                eprintln!("Failed to fetch the exams: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
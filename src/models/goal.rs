@@ -0,0 +1,122 @@
+use crate::models::Goal;
+use crate::schema::goals::dsl::goals;
+use crate::schema::goals::{effective_from, period_id, phase_id, subject_id};
+use crate::schema::phases::dsl::phases;
+use crate::schema::phases::{end_date, start_date};
+use crate::{format_hours_and_minutes, FORMAT};
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::ExpressionMethods;
+use diesel::{QueryDsl, SelectableHelper};
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::fmt::{Display, Formatter};
+use std::process;
+
+impl Display for Goal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let during = match self.phase_id {
+            Some(_) => " (only during its phase)",
+            None => "",
+        };
+        write!(
+            f,
+            "From {}{}: daily {}, weekly {} (ID:{})",
+            self.effective_from.format(FORMAT),
+            during,
+            self.daily_time
+                .map(format_hours_and_minutes)
+                .unwrap_or(String::from("-")),
+            self.weekly_time
+                .map(format_hours_and_minutes)
+                .unwrap_or(String::from("-")),
+            self.id
+        )
+    }
+}
+
+impl Goal {
+    /// Fetches all goals of a period, sorted by the date they are effective from.
+    /// # Arguments
+    /// * `conn` - Database connection
+    /// * `plan_id` - Id of the period.
+    pub fn fetch_by_period(conn: &mut SqliteConnection, plan_id: i32) -> Vec<Goal> {
+        match goals
+            .filter(period_id.eq(plan_id))
+            .order_by(effective_from)
+            .load::<Goal>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the goals: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the goal in effect on a given date. Goals of the phase the date is inside of take priority over the
+    /// general ones. Returns None if there isn't any.
+    /// # Arguments
+    /// * `conn` - Database connection
+    /// * `plan_id` - Id of the period.
+    /// * `subject` - Id of the subject, where None means the goal of the whole period.
+    /// * `on` - Date when the goal is in effect.
+    pub fn effective(
+        conn: &mut SqliteConnection,
+        plan_id: i32,
+        subject: Option<i32>,
+        on: NaiveDate,
+    ) -> Option<Goal> {
+        let mut phase_query = goals
+            .inner_join(phases)
+            .select(Goal::as_select())
+            .filter(period_id.eq(plan_id))
+            .filter(start_date.le(on))
+            .filter(end_date.ge(on))
+            .order_by(effective_from.desc())
+            .into_boxed();
+        phase_query = match subject {
+            Some(s) => phase_query.filter(subject_id.eq(s)),
+            None => phase_query.filter(subject_id.is_null()),
+        };
+        match phase_query.first::<Goal>(conn) {
+            Ok(g) => return Some(g),
+            Err(diesel::NotFound) => {}
+            Err(e) => {
+                eprintln!("Failed to fetch the goal: {e}");
+                process::exit(1);
+            }
+        }
+        Self::general(conn, plan_id, subject, on)
+    }
+
+    /// Gets the general goal (not restricted to a phase) in effect on a given date. Returns None if there isn't any.
+    /// # Arguments
+    /// * `conn` - Database connection
+    /// * `plan_id` - Id of the period.
+    /// * `subject` - Id of the subject, where None means the goal of the whole period.
+    /// * `on` - Date when the goal is in effect.
+    pub fn general(
+        conn: &mut SqliteConnection,
+        plan_id: i32,
+        subject: Option<i32>,
+        on: NaiveDate,
+    ) -> Option<Goal> {
+        let mut query = goals
+            .filter(period_id.eq(plan_id))
+            .filter(phase_id.is_null())
+            .filter(effective_from.le(on))
+            .order_by(effective_from.desc())
+            .into_boxed();
+        query = match subject {
+            Some(s) => query.filter(subject_id.eq(s)),
+            None => query.filter(subject_id.is_null()),
+        };
+        match query.first::<Goal>(conn) {
+            Ok(g) => Some(g),
+            Err(diesel::NotFound) => None,
+            Err(e) => {
+                eprintln!("Failed to fetch the goal: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
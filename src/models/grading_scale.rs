@@ -0,0 +1,187 @@
+//! Grading scales of the final scores.
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Letters of the [GradingScale::Letter] scale, from the highest grade to the lowest.
+const LETTERS: [&str; 5] = ["A", "B", "C", "D", "F"];
+
+/// Grading scale in which the final scores of a period are given.
+///
+/// Scores are converted between scales linearly from zero to the pass mark, and from the pass mark to the maximum
+/// score, so that passing scores are always converted to passing scores.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradingScale {
+    /// Scores from 0 to 10, passing with 5.
+    Ten,
+    /// Scores from 0 to 20, passing with 10.
+    Twenty,
+    /// Letters from F to A. They are stored as grade points (A = 4, B = 3, C = 2, D = 1, F = 0), passing with D.
+    Letter,
+    /// Grade points from 0.0 to 4.0, passing with 1.0.
+    Gpa,
+}
+
+impl Display for GradingScale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GradingScale::Ten => f.write_str("0-10"),
+            GradingScale::Twenty => f.write_str("0-20"),
+            GradingScale::Letter => f.write_str("A-F"),
+            GradingScale::Gpa => f.write_str("4.0"),
+        }
+    }
+}
+
+impl FromStr for GradingScale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "0-10" | "10" => Ok(GradingScale::Ten),
+            "0-20" | "20" => Ok(GradingScale::Twenty),
+            "a-f" | "letter" | "letters" => Ok(GradingScale::Letter),
+            "4.0" | "4" | "gpa" => Ok(GradingScale::Gpa),
+            k => Err(format!(
+                "Unknown grading scale '{k}'. Avaliable scales: 0-10, 0-20, A-F, 4.0"
+            )),
+        }
+    }
+}
+
+impl GradingScale {
+    /// Maximum score of the scale.
+    pub fn max(&self) -> f32 {
+        match self {
+            GradingScale::Ten => 10.0,
+            GradingScale::Twenty => 20.0,
+            GradingScale::Letter | GradingScale::Gpa => 4.0,
+        }
+    }
+
+    /// Minimum score to pass in the scale.
+    pub fn pass_mark(&self) -> f32 {
+        match self {
+            GradingScale::Ten => 5.0,
+            GradingScale::Twenty => 10.0,
+            GradingScale::Letter | GradingScale::Gpa => 1.0,
+        }
+    }
+
+    /// Converts a score of this scale to a value from 0 to 1, where 0.5 is the pass mark.
+    /// # Arguments
+    /// * `score` - Score to convert.
+    fn normalize(&self, score: f32) -> f32 {
+        let score = score.clamp(0.0, self.max());
+        if score >= self.pass_mark() {
+            0.5 + 0.5 * (score - self.pass_mark()) / (self.max() - self.pass_mark())
+        } else {
+            0.5 * score / self.pass_mark()
+        }
+    }
+
+    /// Converts a value from 0 to 1, where 0.5 is the pass mark, to a score of this scale.
+    /// # Arguments
+    /// * `value` - Value to convert.
+    fn denormalize(&self, value: f32) -> f32 {
+        let score = if value >= 0.5 {
+            self.pass_mark() + (value - 0.5) * 2.0 * (self.max() - self.pass_mark())
+        } else {
+            value * 2.0 * self.pass_mark()
+        };
+        match self {
+            // Letters can't take values between grades, so the score is rounded down to the grade achieved.
+            GradingScale::Letter => (score + 1e-4).floor(),
+            _ => score,
+        }
+    }
+
+    /// Converts a score of this scale to another scale.
+    /// # Arguments
+    /// * `score` - Score to convert.
+    /// * `to` - Scale to convert the score to.
+    pub fn convert(&self, score: f32, to: GradingScale) -> f32 {
+        if *self == to {
+            score
+        } else {
+            to.denormalize(self.normalize(score))
+        }
+    }
+
+    /// Determines whether a score of this scale is a passing score.
+    /// # Arguments
+    /// * `score` - Score to check.
+    pub fn passes(&self, score: f32) -> bool {
+        score >= self.pass_mark()
+    }
+
+    /// Formats a score of this scale.
+    /// # Arguments
+    /// * `score` - Score to format.
+    pub fn format_score(&self, score: f32) -> String {
+        match self {
+            GradingScale::Letter => {
+                let index = (4.0 - (score + 1e-4).floor().clamp(0.0, 4.0)) as usize;
+                LETTERS[index].to_string()
+            }
+            _ => format!("{:.2}", score),
+        }
+    }
+
+    /// Parses a score of this scale. Letter scores can be given as letters or grade points. Returns None if the
+    /// score isn't valid in the scale.
+    /// # Arguments
+    /// * `score` - Score to parse.
+    pub fn parse_score(&self, score: &str) -> Option<f32> {
+        if *self == GradingScale::Letter {
+            if let Some(index) = LETTERS
+                .iter()
+                .position(|l| l.eq_ignore_ascii_case(score.trim()))
+            {
+                return Some(4.0 - index as f32);
+            }
+        }
+        match score.trim().parse::<f32>() {
+            Ok(s) if (0.0..=self.max()).contains(&s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-3, "{a} != {b}");
+    }
+
+    #[test]
+    fn convert_keeps_scores_of_the_same_scale() {
+        assert_eq!(GradingScale::Ten.convert(7.3, GradingScale::Ten), 7.3);
+    }
+
+    #[test]
+    fn convert_maps_the_pass_mark_and_the_maximum() {
+        assert_close(GradingScale::Ten.convert(5.0, GradingScale::Twenty), 10.0);
+        assert_close(GradingScale::Ten.convert(10.0, GradingScale::Gpa), 4.0);
+        assert_close(GradingScale::Gpa.convert(1.0, GradingScale::Ten), 5.0);
+    }
+
+    #[test]
+    fn convert_is_linear_on_each_side_of_the_pass_mark() {
+        assert_close(GradingScale::Ten.convert(7.5, GradingScale::Twenty), 15.0);
+        assert_close(GradingScale::Ten.convert(7.5, GradingScale::Gpa), 2.5);
+        assert_close(GradingScale::Ten.convert(2.5, GradingScale::Twenty), 5.0);
+    }
+
+    #[test]
+    fn convert_rounds_letters_down_to_the_grade_achieved() {
+        assert_close(GradingScale::Ten.convert(7.5, GradingScale::Letter), 2.0);
+        assert_close(GradingScale::Ten.convert(4.99, GradingScale::Letter), 0.0);
+    }
+
+    #[test]
+    fn convert_clamps_scores_out_of_the_scale() {
+        assert_close(GradingScale::Ten.convert(12.0, GradingScale::Twenty), 20.0);
+    }
+}
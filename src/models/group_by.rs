@@ -0,0 +1,139 @@
+//! Grouping of subjects in summaries, charts and exports.
+use crate::models::{Entry, Subject};
+use colored::{ColoredString, Colorize};
+use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+use diesel::SqliteConnection;
+use std::str::FromStr;
+
+/// Label of the subjects without category.
+const NO_CATEGORY: &str = "(no category)";
+/// Label of the time without tags.
+const NO_TAG: &str = "(no tag)";
+
+/// How subjects are grouped when their times are summarized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GroupBy {
+    /// Every subject on its own.
+    Subject,
+    /// Subjects grouped by their category (or the one of their parent).
+    Category,
+    /// Subjects grouped under their top-level parent subject.
+    Parent,
+    /// Time grouped by the tags of the entries. The time of an entry with several tags is counted in each of them.
+    Tag,
+    /// Time grouped by the activity types of the entries.
+    Type,
+}
+
+impl FromStr for GroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "subject" => Ok(GroupBy::Subject),
+            "category" => Ok(GroupBy::Category),
+            "parent" => Ok(GroupBy::Parent),
+            "tag" => Ok(GroupBy::Tag),
+            "type" => Ok(GroupBy::Type),
+            _ => Err(format!(
+                "Unknown grouping '{s}'. Avaliable groupings: subject, category, parent, tag, type"
+            )),
+        }
+    }
+}
+
+impl GroupBy {
+    /// Groups the times of some subjects in an interval, keeping the order in which the groups first appear.
+    /// # Arguments
+    /// * `conn` - Database connection, used to fetch the tags and activity types of the entries.
+    /// * `times` - List of (Subject, dedicated time in the interval) tuples. Parents must be in the list too.
+    /// * `interval` - Date interval of the times (start, end).
+    /// * `label` - Label of a subject when it isn't grouped (e.g.: its name or short name).
+    pub fn group(
+        &self,
+        conn: &mut SqliteConnection,
+        times: &[(Subject, i32)],
+        interval: (NaiveDate, NaiveDate),
+        label: impl Fn(&Subject) -> String,
+    ) -> Vec<(ColoredString, i32)> {
+        match self {
+            GroupBy::Tag => return group_tags(conn, times, interval),
+            GroupBy::Type => return group_types(conn, times, interval),
+            _ => {}
+        }
+        let subjects: Vec<Subject> = times.iter().map(|(s, _)| s.clone()).collect();
+        let mut groups: Vec<(ColoredString, i32)> = Vec::new();
+        for (s, t) in times {
+            // Subjects keep their color, and so do parents. Categories aren't colored.
+            let name = match self {
+                GroupBy::Category => s
+                    .effective_category(&subjects)
+                    .unwrap_or(String::from(NO_CATEGORY))
+                    .normal(),
+                GroupBy::Parent => {
+                    let parent = s.top_parent(&subjects);
+                    parent.paint(&label(parent))
+                }
+                _ => s.paint(&label(s)),
+            };
+            match groups.iter_mut().find(|(g, _)| g.input == name.input) {
+                Some((_, total)) => *total += t,
+                None => groups.push((name, *t)),
+            }
+        }
+        groups
+    }
+}
+
+/// Groups the time of the entries of some subjects in an interval by their tags. Untagged time goes last.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `times` - List of (Subject, dedicated time) tuples.
+/// * `interval` - Date interval (start, end).
+fn group_tags(
+    conn: &mut SqliteConnection,
+    times: &[(Subject, i32)],
+    interval: (NaiveDate, NaiveDate),
+) -> Vec<(ColoredString, i32)> {
+    let ids: Vec<i32> = times.iter().map(|(s, _)| s.id).collect();
+    let mut groups: Vec<(String, i32)> = Vec::new();
+    let mut untagged = 0;
+    for e in Entry::fetch_by_subjects(conn, &ids, (Some(interval.0), Some(interval.1))) {
+        let tags = e.tag_list();
+        if tags.is_empty() {
+            untagged += e.dedicated_time;
+        }
+        for t in tags {
+            let name = format!("#{t}");
+            match groups.iter_mut().find(|(g, _)| *g == name) {
+                Some((_, total)) => *total += e.dedicated_time,
+                None => groups.push((name, e.dedicated_time)),
+            }
+        }
+    }
+    if untagged > 0 {
+        groups.push((String::from(NO_TAG), untagged));
+    }
+    groups.into_iter().map(|(g, t)| (g.normal(), t)).collect()
+}
+
+/// Groups the time of the entries of some subjects in an interval by their activity types.
+/// # Arguments
+/// * `conn` - Database connection.
+/// * `times` - List of (Subject, dedicated time) tuples.
+/// * `interval` - Date interval (start, end).
+fn group_types(
+    conn: &mut SqliteConnection,
+    times: &[(Subject, i32)],
+    interval: (NaiveDate, NaiveDate),
+) -> Vec<(ColoredString, i32)> {
+    let ids: Vec<i32> = times.iter().map(|(s, _)| s.id).collect();
+    let mut groups: Vec<(String, i32)> = Vec::new();
+    for e in Entry::fetch_by_subjects(conn, &ids, (Some(interval.0), Some(interval.1))) {
+        match groups.iter_mut().find(|(g, _)| *g == e.activity) {
+            Some((_, total)) => *total += e.dedicated_time,
+            None => groups.push((e.activity, e.dedicated_time)),
+        }
+    }
+    groups.into_iter().map(|(g, t)| (g.normal(), t)).collect()
+}
@@ -1,16 +1,84 @@
 //! Database models and implementations.
 #![allow(dead_code)]
 
+#[doc(hidden)]
+mod activity_type;
+#[doc(hidden)]
+mod assessment;
+#[doc(hidden)]
+mod breaks;
 #[doc(hidden)]
 mod entry;
 #[doc(hidden)]
+mod exam;
+#[doc(hidden)]
+mod goal;
+mod grading_scale;
+mod group_by;
+#[doc(hidden)]
 mod period;
 #[doc(hidden)]
+mod phase;
+#[doc(hidden)]
+mod pomodoro;
+#[doc(hidden)]
+mod program;
+#[doc(hidden)]
+mod session;
+#[doc(hidden)]
+mod setting;
+#[doc(hidden)]
 mod subject;
+#[doc(hidden)]
+mod subject_alias;
 
-use diesel::internal::derives::multiconnection::chrono::NaiveDate;
+pub use activity_type::DEFAULT_ACTIVITY;
+pub use entry::MAX_FOCUS;
+pub use grading_scale::GradingScale;
+pub use group_by::GroupBy;
+pub use setting::DAY_START;
+
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, NaiveDateTime};
 use diesel::{Associations, Queryable, Selectable};
 
+#[derive(Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = crate::schema::activity_types)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+/// Model for a kind of activity the time of an entry can be spent on (study, lecture, homework...).
+pub struct ActivityType {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Queryable, Selectable, Associations, Clone, Debug)]
+#[diesel(table_name = crate::schema::assessments)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(belongs_to(Subject))]
+/// Model for a graded assessment (exam, lab, assignment...) of a subject.
+pub struct Assessment {
+    pub id: i32,
+    pub subject_id: i32,
+    pub name: String,
+    pub weight: f32,
+    pub score: Option<f32>,
+    pub max_score: f32,
+    pub min_score: Option<f32>,
+    pub date: Option<NaiveDate>,
+}
+
+#[derive(Queryable, Selectable, Associations, Clone, Debug)]
+#[diesel(table_name = crate::schema::breaks)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(belongs_to(Period))]
+/// Model for a non-teaching date range (holidays, exam-only weeks...) of a period.
+pub struct Break {
+    pub id: i32,
+    pub period_id: i32,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub label: String,
+}
+
 #[derive(Queryable, Selectable, Clone, Debug)]
 #[diesel(table_name = crate::schema::entry)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
@@ -18,20 +86,124 @@ use diesel::{Associations, Queryable, Selectable};
 /// Model for the dedicated time to a specific subject in a specific date.
 pub struct Entry {
     pub id: i32,
+    /// Study day of the time (see the `day-start` setting), which may be the day before the date on the clock.
     pub date: NaiveDate,
     pub subject_id: i32,
     pub dedicated_time: i32,
+    /// Free-text note about what was studied.
+    pub note: Option<String>,
+    /// Tags of the entry (lowercase, without '#'), separated by commas.
+    pub tags: Option<String>,
+    /// Activity type the time was spent on (study, lecture, homework...).
+    pub activity: String,
+    /// Focus rating of the time of the entry, from 1 to 5 (averaged by time when rated time is added to it).
+    pub focus: Option<f64>,
+}
+
+#[derive(Queryable, Selectable, Associations, Clone, Debug)]
+#[diesel(table_name = crate::schema::exams)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(belongs_to(Subject))]
+/// Model for an exam or deadline (milestone) of a subject.
+pub struct Exam {
+    pub id: i32,
+    pub subject_id: i32,
+    pub date: NaiveDate,
+    pub kind: String,
+    pub weight: Option<f32>,
+    pub description: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Associations, Clone, Debug)]
+#[diesel(table_name = crate::schema::goals)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(belongs_to(Period))]
+/// Model for the daily and weekly study time goals of a subject (or the whole period, if there is no subject)
+/// from a specific date on.
+pub struct Goal {
+    pub id: i32,
+    pub period_id: i32,
+    pub subject_id: Option<i32>,
+    pub effective_from: NaiveDate,
+    pub daily_time: Option<i32>,
+    pub weekly_time: Option<i32>,
+    pub phase_id: Option<i32>,
 }
 
 #[derive(Selectable, Queryable, Clone, Debug)]
 #[diesel(table_name = crate::schema::periods)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
-/// Model for a study period (semester or similar)
+/// Model for a study period (semester or similar). Periods without a final date are open-ended.
 pub struct Period {
     pub id: i32,
     pub initial_date: NaiveDate,
-    pub final_date: NaiveDate,
+    pub final_date: Option<NaiveDate>,
     pub description: String,
+    pub grading_scale: String,
+    pub program_id: Option<i32>,
+    pub academic_year: Option<i32>,
+}
+
+#[derive(Queryable, Selectable, Associations, Clone, Debug)]
+#[diesel(table_name = crate::schema::phases)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(belongs_to(Period))]
+/// Model for a named phase (teaching, revision, exams...) of a period.
+pub struct Phase {
+    pub id: i32,
+    pub period_id: i32,
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Queryable, Selectable, Associations, Clone, Debug)]
+#[diesel(table_name = crate::schema::pomodoros)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(belongs_to(Subject))]
+/// Model for a pomodoro (a focus interval) run with the pomodoro command.
+pub struct Pomodoro {
+    pub id: i32,
+    pub subject_id: i32,
+    pub started_at: NaiveDateTime,
+    /// Planned length of the pomodoro.
+    pub work_minutes: i32,
+    /// Minutes it lasted (less than planned if it was interrupted).
+    pub focused_minutes: i32,
+    /// Whether it lasted as planned, without being interrupted.
+    pub completed: bool,
+}
+
+#[derive(Queryable, Selectable, Associations, Clone, Debug)]
+#[diesel(table_name = crate::schema::sessions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(belongs_to(Subject))]
+/// Model for a study session with a start time (logged with `add --at` or by completing a pomodoro).
+pub struct Session {
+    pub id: i32,
+    pub subject_id: i32,
+    /// Date and time the session started (as on the clock, regardless of the start of the study day).
+    pub started_at: NaiveDateTime,
+    pub minutes: i32,
+}
+
+#[derive(Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = crate::schema::settings)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+/// Model for a user setting.
+pub struct Setting {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = crate::schema::programs)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+/// Model for a program (degree) that groups periods into academic years.
+pub struct Program {
+    pub id: i32,
+    pub name: String,
+    pub required_credits: f32,
 }
 
 #[derive(Queryable, Selectable, Associations, Clone, Debug)]
@@ -45,4 +217,27 @@ pub struct Subject {
     pub short_name: String,
     pub name: String,
     pub final_score: Option<f32>,
+    pub credits: Option<f32>,
+    pub passed: Option<bool>,
+    /// Subjects of different periods with the same course are the same course (e.g.: a year-long subject).
+    pub course_id: Option<i32>,
+    /// Category of the subject (math, lab, electives...). Subjects without one take the category of their parent.
+    pub category: Option<String>,
+    /// Parent subject, from the same period.
+    pub parent_id: Option<i32>,
+    /// Color used to print the subject (a color name, or an hexadecimal color like #ff8800).
+    pub color: Option<String>,
+    /// Date since which the subject is archived (e.g.: it was dropped), if it is.
+    pub archived_at: Option<NaiveDate>,
+}
+
+#[derive(Queryable, Selectable, Associations, Clone, Debug)]
+#[diesel(table_name = crate::schema::subject_aliases)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(belongs_to(Subject))]
+/// Model for another name a subject can be referred to by, besides its short name.
+pub struct SubjectAlias {
+    pub id: i32,
+    pub subject_id: i32,
+    pub alias: String,
 }
@@ -1,16 +1,17 @@
-use crate::models::{Period, Subject};
+use crate::models::{Break, GradingScale, Period, Subject};
 use crate::schema::entry::date;
 use crate::schema::entry::dsl::entry;
 use crate::schema::periods::dsl::periods;
 use crate::schema::periods::{final_date, initial_date};
 use crate::schema::subjects::dsl::subjects;
 use crate::schema::subjects::period_id;
-use crate::{debug_println, FORMAT};
-use diesel::dsl::sql;
-use diesel::internal::derives::multiconnection::chrono::{Local, NaiveDate};
+use crate::{debug_println, today, FORMAT};
+use diesel::dsl;
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, TimeDelta, Weekday};
 use diesel::ExpressionMethods;
-use diesel::QueryDsl;
+use diesel::{BoolExpressionMethods, QueryDsl};
 use diesel::{RunQueryDsl, SqliteConnection};
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::process;
 
@@ -20,7 +21,7 @@ impl Display for Period {
             f,
             "{} - {}\t{} (ID:{})",
             self.initial_date.format(FORMAT),
-            self.final_date.format(FORMAT),
+            self.format_end(),
             self.description,
             self.id
         )
@@ -28,11 +29,47 @@ impl Display for Period {
 }
 
 impl Period {
+    /// Gets the grading scale of the final scores of the period.
+    pub fn scale(&self) -> GradingScale {
+        match self.grading_scale.parse::<GradingScale>() {
+            Ok(scale) => scale,
+            Err(e) => {
+                debug_println!("{e}");
+                GradingScale::Ten
+            }
+        }
+    }
+
+    /// Gets the last day of the period. Open-ended periods never end, so the maximum date is returned for them.
+    /// It is meant for comparisons and clamping, so it shouldn't be iterated up to nor stored.
+    pub fn end_date(&self) -> NaiveDate {
+        self.final_date.unwrap_or(NaiveDate::MAX)
+    }
+
+    /// Gets the last day of the period, where open-ended periods end today (or on their first day, if they
+    /// haven't started yet).
+    pub fn end_or_today(&self) -> NaiveDate {
+        self.final_date.unwrap_or(today().max(self.initial_date))
+    }
+
+    /// Formats the last day of the period, or `open` if it is open-ended.
+    pub fn format_end(&self) -> String {
+        match self.final_date {
+            Some(d) => d.format(FORMAT).to_string(),
+            None => String::from("open"),
+        }
+    }
+
     /// It determines if the period is actual (It is ocurring now)
     pub fn is_actual(&self) -> bool {
-        let now = Local::now().date_naive();
+        self.contains(today())
+    }
 
-        now >= self.initial_date && now <= self.final_date
+    /// It determines whether a day is inside the period.
+    /// # Arguments
+    /// * `day` - Day to check.
+    pub fn contains(&self, day: NaiveDate) -> bool {
+        day >= self.initial_date && day <= self.end_date()
     }
     /// It fetches all study periods.
     /// # Arguments
@@ -48,25 +85,34 @@ impl Period {
         }
     }
 
-    /// Gets the period given a date. Returns None if there isn't any.
+    /// Gets the period given a date. If there is more than one (periods may overlap), the one that started the
+    /// latest is returned. Returns None if there isn't any.
     /// # Arguments
     /// * `conn` - Database connection.
     /// * `period_date` - Date of the period.
     pub fn from_date(conn: &mut SqliteConnection, period_date: &NaiveDate) -> Option<Period> {
+        let active = Self::fetch_active(conn, period_date);
+        if active.len() > 1 {
+            debug_println!(
+                "There is more than one period ocurring now! Content: {:?}",
+                active
+            );
+        }
+        active.last().cloned()
+    }
+
+    /// Gets every period ocurring on a date, sorted by start date.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `period_date` - Date of the periods.
+    pub fn fetch_active(conn: &mut SqliteConnection, period_date: &NaiveDate) -> Vec<Period> {
         match periods
             .filter(initial_date.le(period_date))
-            .filter(final_date.ge(period_date))
+            .filter(final_date.ge(period_date).or(final_date.is_null()))
+            .order_by((initial_date, crate::schema::periods::id))
             .load::<Period>(conn)
         {
-            Ok(period) => {
-                if period.len() > 1 {
-                    debug_println!(
-                        "There is more than one period ocurring now! Content: {:?}",
-                        period
-                    );
-                }
-                period.first().cloned()
-            }
+            Ok(p) => p,
             Err(e) => {
                 eprintln!("Failed to load: {e}");
                 process::exit(1);
@@ -95,19 +141,19 @@ impl Period {
     /// # Arguments
     /// * `conn` - Database connection
     pub fn get_actual_period(conn: &mut SqliteConnection) -> Option<Period> {
-        Self::from_date(conn, &Local::now().date_naive())
+        Self::from_date(conn, &today())
     }
 
     /// It determines whether the period is overlaping another.
     pub fn overlaps_period(&self, other: &Period) -> bool {
-        let p2 = (other.initial_date, other.final_date);
+        let p2 = (other.initial_date, other.end_date());
         self.overlaps(p2)
     }
     /// It determines whether the period is overlaping the period between `dates`.
     /// # Arguments
     /// * `dates` - Date interval (start, end)
     pub fn overlaps(&self, dates: (NaiveDate, NaiveDate)) -> bool {
-        let p1 = (self.initial_date, self.final_date);
+        let p1 = (self.initial_date, self.end_date());
         (p1.0 <= dates.1 && p1.0 >= dates.0)
             || (p1.1 <= dates.1 && p1.1 >= dates.0)
             || (p1.0 <= dates.0 && p1.1 >= dates.0)
@@ -126,7 +172,8 @@ impl Period {
         }
     }
 
-    /// Gets the global dedicated time average by weeks until de given day.
+    /// Gets the global dedicated time average by weeks until de given day. The days of the breaks of the period
+    /// are excluded, so weeks fully inside a break don't count and weeks partly inside one count as a fraction.
     /// # Arguments
     /// * `conn` - Database connection.
     /// * `until` - Day which previous week is the last to be calculated.
@@ -138,22 +185,91 @@ impl Period {
         until: NaiveDate,
     ) -> f64 {
         let start = from.max(self.initial_date);
-        let end = until.min(self.final_date);
-        let weekly_sum = entry
-            .select((
-                sql::<diesel::sql_types::Integer>("strftime('%Y', date)"),
-                sql::<diesel::sql_types::Integer>("strftime('%W', date)"),
-                sql::<diesel::sql_types::Nullable<diesel::sql_types::Integer>>(
-                    "SUM(dedicated_time) AS suma",
-                ),
-            ))
+        let end = until.min(self.end_date());
+        // Plans may overlap, so only the entries of the subjects of this period are counted.
+        let mut query = entry
+            .inner_join(subjects)
+            .filter(period_id.eq(self.id))
             .filter(date.between(start, end))
-            .group_by((
-                sql::<diesel::sql_types::Integer>("strftime('%Y', date)"),
-                sql::<diesel::sql_types::Integer>("strftime('%W', date)"),
-            ))
-            .load::<(i32, i32, Option<i32>)>(conn)
+            .group_by(date)
+            .select((date, dsl::sum(crate::schema::entry::dedicated_time)))
+            .into_boxed();
+        let breaks = Break::fetch_by_period(conn, self.id);
+        for b in &breaks {
+            query = query.filter(date.not_between(b.start_date, b.end_date));
+        }
+        let daily_sum = query
+            .load::<(NaiveDate, Option<i64>)>(conn)
             .expect("Failed to fetch the weekly average");
-        weekly_sum.iter().map(|t| t.2.unwrap_or(0)).sum::<i32>() as f64 / (weekly_sum.len() as f64)
+        let mut weekly_sum: HashMap<NaiveDate, i64> = HashMap::new();
+        for (day, time) in daily_sum {
+            *weekly_sum
+                .entry(day.week(Weekday::Mon).first_day())
+                .or_default() += time.unwrap_or(0);
+        }
+        let weeks: f64 = weekly_sum
+            .keys()
+            .map(|first| {
+                let teaching_days = (0..7)
+                    .map(|i| *first + TimeDelta::days(i))
+                    .filter(|d| !breaks.iter().any(|b| b.contains(*d)))
+                    .count();
+                teaching_days as f64 / 7.0
+            })
+            .sum();
+        weekly_sum.values().sum::<i64>() as f64 / weeks
+    }
+
+    /// Gets the dedicated time of every day of the period that has at least one entry.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `subject` - If provided, only the entries of this subject are counted.
+    pub fn daily_dedicated_time(
+        &self,
+        conn: &mut SqliteConnection,
+        subject: Option<&Subject>,
+    ) -> HashMap<NaiveDate, i32> {
+        let mut query = entry
+            .inner_join(subjects)
+            .filter(period_id.eq(self.id))
+            .filter(date.ge(self.initial_date))
+            .group_by(date)
+            .select((date, dsl::sum(crate::schema::entry::dedicated_time)))
+            .into_boxed();
+        if let Some(end) = self.final_date {
+            query = query.filter(date.le(end));
+        }
+        if let Some(subject) = subject {
+            query = query.filter(crate::schema::entry::subject_id.eq(subject.id));
+        }
+        match query.load::<(NaiveDate, Option<i64>)>(conn) {
+            Ok(days) => days
+                .into_iter()
+                .map(|(d, t)| (d, t.unwrap_or(0) as i32))
+                .collect(),
+            Err(e) => {
+                eprintln!("Failed to fetch the daily dedicated time: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the total dedicated time to all the subjects of the period in an interval.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `interval` - Date interval (start, end).
+    pub fn total_dedicated_time_interval(
+        &self,
+        conn: &mut SqliteConnection,
+        interval: (NaiveDate, NaiveDate),
+    ) -> i32 {
+        entry
+            .inner_join(subjects)
+            .select(dsl::sum(crate::schema::entry::dedicated_time))
+            .filter(period_id.eq(self.id))
+            .filter(date.between(interval.0, interval.1))
+            .first::<Option<i64>>(conn)
+            .expect("Error loading entry")
+            .unwrap_or(0) as i32
     }
 }
@@ -0,0 +1,117 @@
+use crate::models::{Period, Phase};
+use crate::schema::periods::dsl::periods;
+use crate::schema::phases::dsl::phases;
+use crate::schema::phases::{period_id, start_date};
+use crate::FORMAT;
+use diesel::internal::derives::multiconnection::chrono::{NaiveDate, TimeDelta};
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::fmt::{Display, Formatter};
+use std::process;
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} - {}\t{} (ID:{})",
+            self.start_date.format(FORMAT),
+            self.end_date.format(FORMAT),
+            self.name,
+            self.id
+        )
+    }
+}
+
+impl Phase {
+    /// Fetches all phases of a period, sorted by start date.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `period` - Id of the period.
+    pub fn fetch_by_period(conn: &mut SqliteConnection, period: i32) -> Vec<Phase> {
+        match phases
+            .filter(period_id.eq(period))
+            .order_by(start_date)
+            .load::<Phase>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the phases: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Fetches the phases with a given name (ignoring case) of every period, sorted by start date, along with
+    /// their period.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `phase_name` - Name of the phases.
+    pub fn fetch_by_name(conn: &mut SqliteConnection, phase_name: &str) -> Vec<(Phase, Period)> {
+        match phases
+            .inner_join(periods)
+            .order_by(start_date)
+            .load::<(Phase, Period)>(conn)
+        {
+            Ok(v) => v
+                .into_iter()
+                .filter(|(p, _)| p.name.eq_ignore_ascii_case(phase_name.trim()))
+                .collect(),
+            Err(e) => {
+                eprintln!("Failed to fetch the phases: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Determines whether a day is inside the phase.
+    /// # Arguments
+    /// * `day` - Day to check.
+    pub fn contains(&self, day: NaiveDate) -> bool {
+        day >= self.start_date && day <= self.end_date
+    }
+
+    /// Gets the phase a day is inside of, if any.
+    /// # Arguments
+    /// * `list` - Phases of the period.
+    /// * `day` - Day to check.
+    pub fn on(list: &[Phase], day: NaiveDate) -> Option<&Phase> {
+        list.iter().find(|p| p.contains(day))
+    }
+
+    /// Gets the names of the phases that overlap an interval, separated by slashes.
+    /// # Arguments
+    /// * `list` - Phases of the period.
+    /// * `interval` - Date interval (start, end).
+    pub fn names_overlapping(list: &[Phase], interval: (NaiveDate, NaiveDate)) -> String {
+        list.iter()
+            .filter(|p| p.start_date <= interval.1 && p.end_date >= interval.0)
+            .map(|p| p.name.clone())
+            .collect::<Vec<String>>()
+            .join("/")
+    }
+
+    /// Number of weeks of the phase, counted from its first day.
+    pub fn weeks(&self) -> i64 {
+        ((self.end_date - self.start_date).num_days() + 7) / 7
+    }
+
+    /// Gets the week of the phase (starting at 1, counted from its first day) a day is in.
+    /// # Arguments
+    /// * `day` - Day inside the phase.
+    pub fn week_of(&self, day: NaiveDate) -> i64 {
+        (day - self.start_date).num_days() / 7 + 1
+    }
+
+    /// Gets the interval of a week of the phase (starting at 1, counted from its first day). Returns None if the
+    /// phase doesn't have that week.
+    /// # Arguments
+    /// * `week` - Number of the week.
+    pub fn week_interval(&self, week: i64) -> Option<(NaiveDate, NaiveDate)> {
+        if week < 1 || week > self.weeks() {
+            return None;
+        }
+        let start = self.start_date + TimeDelta::weeks(week - 1);
+        Some((start, (start + TimeDelta::days(6)).min(self.end_date)))
+    }
+}
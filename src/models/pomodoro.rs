@@ -0,0 +1,60 @@
+use crate::models::Pomodoro;
+use crate::schema::pomodoros::dsl::pomodoros;
+use crate::schema::pomodoros::{completed, focused_minutes, started_at, subject_id, work_minutes};
+use diesel::internal::derives::multiconnection::chrono::NaiveDateTime;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{insert_into, RunQueryDsl, SqliteConnection};
+use std::process;
+
+impl Pomodoro {
+    /// Records a pomodoro.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `subject` - Id of the subject studied.
+    /// * `start` - When the pomodoro started.
+    /// * `planned` - Planned length, in minutes.
+    /// * `focused` - Minutes it lasted.
+    pub fn record(
+        conn: &mut SqliteConnection,
+        subject: i32,
+        start: NaiveDateTime,
+        planned: i32,
+        focused: i32,
+    ) {
+        match insert_into(pomodoros)
+            .values((
+                subject_id.eq(subject),
+                started_at.eq(start),
+                work_minutes.eq(planned),
+                focused_minutes.eq(focused),
+                completed.eq(focused >= planned),
+            ))
+            .execute(conn)
+        {
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Failed to record the pomodoro: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Fetches the pomodoros of some subjects, sorted by start.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `subject_ids` - Ids of the subjects.
+    pub fn fetch_by_subjects(conn: &mut SqliteConnection, subject_ids: &[i32]) -> Vec<Pomodoro> {
+        match pomodoros
+            .filter(subject_id.eq_any(subject_ids))
+            .order_by(started_at)
+            .load::<Pomodoro>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the pomodoros: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
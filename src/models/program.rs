@@ -0,0 +1,66 @@
+use crate::models::{Period, Program};
+use crate::schema::periods::dsl::periods;
+use crate::schema::periods::{academic_year, initial_date, program_id};
+use crate::schema::programs::dsl::programs;
+use crate::schema::programs::id;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::fmt::{Display, Formatter};
+use std::process;
+
+impl Display for Program {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, {} credits required (ID:{})",
+            self.name, self.required_credits, self.id
+        )
+    }
+}
+
+impl Program {
+    /// Fetches all programs.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    pub fn fetch_all(conn: &mut SqliteConnection) -> Vec<Program> {
+        match programs.order_by(id).load::<Program>(conn) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the programs: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the program given its id. Returns None if there isn't any.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `id_to_fetch` - Program id.
+    pub fn from_id(conn: &mut SqliteConnection, id_to_fetch: i32) -> Option<Program> {
+        match programs.filter(id.eq(id_to_fetch)).load::<Program>(conn) {
+            Ok(p) => p.first().cloned(),
+            Err(e) => {
+                eprintln!("Failed to fetch program: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Fetches the periods of the program, sorted by academic year and start date.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    pub fn fetch_periods(&self, conn: &mut SqliteConnection) -> Vec<Period> {
+        match periods
+            .filter(program_id.eq(self.id))
+            .order_by((academic_year, initial_date))
+            .load::<Period>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the periods of the program: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
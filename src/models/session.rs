@@ -0,0 +1,142 @@
+use crate::models::{Session, Subject};
+use crate::schema::sessions::dsl::sessions;
+use crate::schema::sessions::{id, minutes, started_at, subject_id};
+use crate::study_day;
+use diesel::internal::derives::multiconnection::chrono::{
+    NaiveDate, NaiveDateTime, TimeDelta, Timelike,
+};
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, insert_into, update, RunQueryDsl, SqliteConnection};
+use std::process;
+
+impl Session {
+    /// Records a study session.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `subject` - Id of the subject studied.
+    /// * `start` - When the session started.
+    /// * `length` - Minutes studied.
+    pub fn record(conn: &mut SqliteConnection, subject: i32, start: NaiveDateTime, length: i32) {
+        match insert_into(sessions)
+            .values((
+                subject_id.eq(subject),
+                started_at.eq(start),
+                minutes.eq(length),
+            ))
+            .execute(conn)
+        {
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Failed to record the session: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Fetches the sessions of some subjects, sorted by start.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `subject_ids` - Ids of the subjects.
+    pub fn fetch_by_subjects(conn: &mut SqliteConnection, subject_ids: &[i32]) -> Vec<Session> {
+        match sessions
+            .filter(subject_id.eq_any(subject_ids))
+            .order_by(started_at)
+            .load::<Session>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the sessions: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Trims the sessions of a subject on a study day after its time was reduced (substracted, set, edited or
+    /// deleted), so they don't add up to more than the time logged that day. The latest sessions are trimmed first.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `subject` - Subject whose time was reduced.
+    /// * `day` - Study day of the time.
+    pub fn trim(conn: &mut SqliteConnection, subject: &Subject, day: NaiveDate) {
+        let mut of_day: Vec<Session> = Self::fetch_by_subjects(conn, &[subject.id])
+            .into_iter()
+            .filter(|s| study_day(s.started_at) == day)
+            .collect();
+        let logged = subject.total_dedicated_time_day(day, conn);
+        let mut excess = of_day.iter().map(|s| s.minutes).sum::<i32>() - logged;
+        while excess > 0 {
+            let last = of_day.pop().unwrap();
+            let result = match last.minutes > excess {
+                true => update(sessions.filter(id.eq(last.id)))
+                    .set(minutes.eq(last.minutes - excess))
+                    .execute(conn),
+                false => delete(sessions.filter(id.eq(last.id))).execute(conn),
+            };
+            if let Err(e) = result {
+                eprintln!("Failed to trim the sessions: {e}");
+                process::exit(1);
+            }
+            excess -= last.minutes;
+        }
+    }
+
+    /// Splits the session by the hours it spans. Returns a list of (start of the hour, minutes studied in it)
+    /// tuples, so a session from 23:30 to 01:00 is split into 30 minutes at 23:00 and 60 minutes at 00:00.
+    pub fn split_by_hour(&self) -> Vec<(NaiveDateTime, i32)> {
+        let mut parts = Vec::new();
+        let mut current = self.started_at;
+        let mut left = self.minutes;
+        while left > 0 {
+            let in_hour = (60 - current.minute() as i32).min(left);
+            let hour = current.date().and_hms_opt(current.hour(), 0, 0).unwrap();
+            parts.push((hour, in_hour));
+            left -= in_hour;
+            current = hour + TimeDelta::hours(1);
+        }
+        parts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(start: &str, length: i32) -> Session {
+        Session {
+            id: 1,
+            subject_id: 1,
+            started_at: at(start),
+            minutes: length,
+        }
+    }
+
+    fn at(moment: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(moment, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn split_by_hour_keeps_sessions_inside_one_hour() {
+        assert_eq!(
+            session("2026-10-19 10:15", 20).split_by_hour(),
+            vec![(at("2026-10-19 10:00"), 20)]
+        );
+        assert_eq!(
+            session("2026-10-19 10:00", 60).split_by_hour(),
+            vec![(at("2026-10-19 10:00"), 60)]
+        );
+    }
+
+    #[test]
+    fn split_by_hour_splits_across_midnight() {
+        assert_eq!(
+            session("2026-10-19 23:30", 90).split_by_hour(),
+            vec![(at("2026-10-19 23:00"), 30), (at("2026-10-20 00:00"), 60)]
+        );
+    }
+
+    #[test]
+    fn split_by_hour_of_an_empty_session_is_empty() {
+        assert!(session("2026-10-19 10:00", 0).split_by_hour().is_empty());
+    }
+}
@@ -0,0 +1,73 @@
+use crate::models::Setting;
+use crate::schema::settings::dsl::settings;
+use crate::schema::settings::{name, value};
+use diesel::internal::derives::multiconnection::chrono::NaiveTime;
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{delete, replace_into, RunQueryDsl, SqliteConnection};
+use std::process;
+
+/// Name of the setting with the time of the day when a study day starts (HH:MM).
+pub const DAY_START: &str = "day-start";
+
+impl Setting {
+    /// Gets the value of a setting. Returns None if it isn't set.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `setting` - Name of the setting.
+    pub fn get(conn: &mut SqliteConnection, setting: &str) -> Option<String> {
+        match settings
+            .filter(name.eq(setting))
+            .select(value)
+            .first::<String>(conn)
+        {
+            Ok(v) => Some(v),
+            Err(diesel::NotFound) => None,
+            Err(e) => {
+                eprintln!("Failed to fetch the setting {setting}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Sets the value of a setting, replacing the previous one.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `setting` - Name of the setting.
+    /// * `new_value` - Value to set.
+    pub fn set(conn: &mut SqliteConnection, setting: &str, new_value: &str) {
+        match replace_into(settings)
+            .values((name.eq(setting), value.eq(new_value)))
+            .execute(conn)
+        {
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Failed to save the setting {setting}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Removes a setting, so its default value is used.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `setting` - Name of the setting.
+    pub fn unset(conn: &mut SqliteConnection, setting: &str) {
+        match delete(settings.filter(name.eq(setting))).execute(conn) {
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Failed to reset the setting {setting}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the time of the day when a study day starts (midnight unless it is set).
+    /// # Arguments
+    /// * `conn` - Database connection.
+    pub fn day_start(conn: &mut SqliteConnection) -> NaiveTime {
+        Self::get(conn, DAY_START)
+            .and_then(|t| NaiveTime::parse_from_str(&t, "%H:%M").ok())
+            .unwrap_or(NaiveTime::MIN)
+    }
+}
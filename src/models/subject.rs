@@ -1,32 +1,120 @@
-use crate::models::{Entry, Subject};
+use crate::models::{Entry, GradingScale, Subject};
 use crate::schema::entry::dsl::entry;
-use crate::schema::entry::{date, dedicated_time, subject_id};
+use crate::schema::entry::{activity, date, dedicated_time, subject_id};
+use crate::schema::periods::dsl::periods;
+use crate::schema::periods::initial_date;
+use crate::schema::subjects::course_id;
 use crate::schema::subjects::dsl::subjects;
+use crate::FORMAT;
+use colored::{Color, ColoredString, Colorize};
 use diesel::dsl;
 use diesel::internal::derives::multiconnection::chrono::{NaiveDate, NaiveWeek};
-use diesel::QueryDsl;
 use diesel::SqliteConnection;
 use diesel::{ExpressionMethods, RunQueryDsl};
+use diesel::{QueryDsl, SelectableHelper};
 use std::fmt::{Display, Formatter};
 use std::process;
 
+/// Colors given to new subjects, in order. Subjects without a stored color use the one at their id (modulo the
+/// length of the palette).
+pub const PALETTE: [&str; 12] = [
+    "cyan",
+    "magenta",
+    "yellow",
+    "green",
+    "blue",
+    "red",
+    "bright cyan",
+    "bright magenta",
+    "bright yellow",
+    "bright green",
+    "bright blue",
+    "bright red",
+];
+
 impl Display for Subject {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if let Some(final_score) = self.final_score {
             write!(
                 f,
                 "{} ({}) [{}]",
-                self.name,
+                self.paint(&self.name),
                 self.short_name,
                 final_score
             )
         } else {
-            write!(f, "{} ({})", self.name, self.short_name)
+            write!(f, "{} ({})", self.paint(&self.name), self.short_name)
         }
     }
 }
 
 impl Subject {
+    /// Determines whether the subject is passed. If its pass/fail state isn't set, it is determined by its final
+    /// score. Returns None if the subject has neither a state nor a final score.
+    /// # Arguments
+    /// * `scale` - Grading scale of the final score.
+    pub fn is_passed(&self, scale: GradingScale) -> Option<bool> {
+        match self.passed {
+            Some(passed) => Some(passed),
+            None => self.final_score.map(|s| scale.passes(s)),
+        }
+    }
+
+    /// Determines whether the subject is archived on a date. Archived subjects are kept for the dates they were
+    /// taken, but no time can be logged to them from their archive date on.
+    /// # Arguments
+    /// * `day` - Date to check.
+    pub fn is_archived(&self, day: NaiveDate) -> bool {
+        self.archived_at.is_some_and(|a| a <= day)
+    }
+
+    /// Exits with an error if the subject is archived on a date, since no time can be logged to it then.
+    /// # Arguments
+    /// * `day` - Date the time would be logged to.
+    pub fn exit_if_archived(&self, day: NaiveDate) {
+        if let Some(a) = self.archived_at.filter(|_| self.is_archived(day)) {
+            eprintln!(
+                "{} is archived since {}, so no time can be logged to it from that date on. Use `subject unarchive` if you take it again.",
+                self.name,
+                a.format(FORMAT)
+            );
+            process::exit(1);
+        }
+    }
+
+    /// Gets the color of the subject.
+    pub fn color(&self) -> Color {
+        match self.color.as_ref().and_then(|c| c.parse::<Color>().ok()) {
+            Some(c) => c,
+            None => PALETTE[self.id as usize % PALETTE.len()].into(),
+        }
+    }
+
+    /// Paints a text (usually the name of the subject) with the color of the subject. Colors are left out if they
+    /// are disabled (`NO_COLOR`, `--color never` or not printing to a terminal).
+    /// # Arguments
+    /// * `text` - Text to paint.
+    pub fn paint(&self, text: &str) -> ColoredString {
+        text.color(self.color())
+    }
+
+    /// Gets the color for a new subject of a period: the first color of the palette that isn't used in the period
+    /// yet, or the next one in the palette if all of them are.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `plan_id` - Id of the period of the new subject.
+    pub fn next_color(conn: &mut SqliteConnection, plan_id: i32) -> String {
+        let used = Self::fetch_all(conn)
+            .into_iter()
+            .filter(|s| s.period_id == plan_id)
+            .filter_map(|s| s.color)
+            .collect::<Vec<String>>();
+        match PALETTE.iter().find(|c| !used.iter().any(|u| u == *c)) {
+            Some(c) => c.to_string(),
+            None => PALETTE[used.len() % PALETTE.len()].to_string(),
+        }
+    }
+
     /// Gets the total dedicated time of the subject
     /// # Arguments
     /// * `conn` - Database connection
@@ -59,26 +147,39 @@ impl Subject {
     pub fn total_dedicated_time_week(&self, conn: &mut SqliteConnection, week: NaiveWeek) -> i32 {
         self.total_dedicated_time_interval(conn, (week.first_day(), week.last_day()))
     }
-    /// Gets the dedicated time to a subject in a determined day. If there was no entry regarding that date, returns zero.
+    /// Gets the dedicated time to a subject in a determined day, of every activity type. If there was no entry
+    /// regarding that date, returns zero.
     /// # Arguments
     /// * `date_to_fetch` - date to search.
-    /// * `subject_to_fetch` - subject id to search.
     /// * `conn` - connection to the database.
     pub fn total_dedicated_time_day(
         &self,
         date_to_fetch: NaiveDate,
         conn: &mut SqliteConnection,
     ) -> i32 {
-        match entry
-            .filter(date.eq(&date_to_fetch))
+        self.total_dedicated_time_interval(conn, (date_to_fetch, date_to_fetch))
+    }
+
+    /// Gets the time dedicated to the subject in an interval doing an activity type.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `interval` - Date interval (start, end).
+    /// * `activity_name` - Activity type.
+    pub fn activity_time_interval(
+        &self,
+        conn: &mut SqliteConnection,
+        interval: (NaiveDate, NaiveDate),
+        activity_name: &str,
+    ) -> i32 {
+        entry
+            .select(dsl::sum(dedicated_time))
             .filter(subject_id.eq(self.id))
-            .load::<Entry>(conn)
-            .expect("Failed to fetch entries")
-            .first()
-        {
-            Some(e) => e.dedicated_time,
-            None => 0,
-        }
+            .filter(activity.eq(activity_name))
+            .filter(date.ge(interval.0))
+            .filter(date.le(interval.1))
+            .first::<Option<i64>>(conn)
+            .expect("Error loading entry")
+            .unwrap_or(0) as i32
     }
 
     /// Fetches all entries related to the subject.
@@ -94,6 +195,78 @@ impl Subject {
         }
     }
 
+    /// Fetches every subject of the course of the subject (itself included), sorted by the start of their periods.
+    /// If the subject isn't linked to any other, only the subject is returned.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    pub fn fetch_course(&self, conn: &mut SqliteConnection) -> Vec<Subject> {
+        let course = match self.course_id {
+            Some(c) => c,
+            None => return vec![self.clone()],
+        };
+        match subjects
+            .inner_join(periods)
+            .filter(course_id.eq(course))
+            .order_by(initial_date)
+            .select(Subject::as_select())
+            .load::<Subject>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the course: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Gets the total dedicated time of the whole course of the subject.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    pub fn course_dedicated_time(&self, conn: &mut SqliteConnection) -> i32 {
+        self.fetch_course(conn)
+            .iter()
+            .map(|s| s.total_dedicated_time(conn))
+            .sum()
+    }
+
+    /// Gets the top-level parent of the subject (itself, if it hasn't a parent).
+    /// # Arguments
+    /// * `list` - Subjects of the period.
+    pub fn top_parent<'a>(&'a self, list: &'a [Subject]) -> &'a Subject {
+        let mut current = self;
+        // The depth is limited by the number of subjects, so a cycle can't loop forever.
+        for _ in 0..list.len() {
+            match current
+                .parent_id
+                .and_then(|p| list.iter().find(|s| s.id == p))
+            {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+        current
+    }
+
+    /// Gets the category of the subject, or the one of its nearest ancestor with a category.
+    /// # Arguments
+    /// * `list` - Subjects of the period.
+    pub fn effective_category(&self, list: &[Subject]) -> Option<String> {
+        let mut current = self;
+        for _ in 0..=list.len() {
+            if current.category.is_some() {
+                return current.category.clone();
+            }
+            match current
+                .parent_id
+                .and_then(|p| list.iter().find(|s| s.id == p))
+            {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+        None
+    }
+
     /// Fetches all subjects from the database.
     /// # Arguments:
     /// * `conn` - Database connection.
@@ -0,0 +1,27 @@
+use crate::models::SubjectAlias;
+use crate::schema::subject_aliases::dsl::subject_aliases;
+use crate::schema::subject_aliases::{id, subject_id};
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::{RunQueryDsl, SqliteConnection};
+use std::process;
+
+impl SubjectAlias {
+    /// Fetches the aliases of some subjects, sorted by id.
+    /// # Arguments
+    /// * `conn` - Database connection.
+    /// * `subject_ids` - Ids of the subjects.
+    pub fn fetch_for(conn: &mut SqliteConnection, subject_ids: &[i32]) -> Vec<SubjectAlias> {
+        match subject_aliases
+            .filter(subject_id.eq_any(subject_ids))
+            .order_by(id)
+            .load::<SubjectAlias>(conn)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to fetch the aliases: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -1,11 +1,78 @@
 //! Module generated automatically by Diesel CLI
 
+diesel::table! {
+    activity_types (id) {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    assessments (id) {
+        id -> Integer,
+        subject_id -> Integer,
+        name -> Text,
+        weight -> Float,
+        score -> Nullable<Float>,
+        max_score -> Float,
+        min_score -> Nullable<Float>,
+        date -> Nullable<Date>,
+    }
+}
+
+diesel::table! {
+    breaks (id) {
+        id -> Integer,
+        period_id -> Integer,
+        start_date -> Date,
+        end_date -> Date,
+        label -> Text,
+    }
+}
+
 diesel::table! {
     entry (id) {
         id -> Integer,
         date -> Date,
         subject_id -> Integer,
         dedicated_time -> Integer,
+        note -> Nullable<Text>,
+        tags -> Nullable<Text>,
+        activity -> Text,
+        focus -> Nullable<Double>,
+    }
+}
+
+diesel::table! {
+    exams (id) {
+        id -> Integer,
+        subject_id -> Integer,
+        date -> Date,
+        kind -> Text,
+        weight -> Nullable<Float>,
+        description -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    goals (id) {
+        id -> Integer,
+        period_id -> Integer,
+        subject_id -> Nullable<Integer>,
+        effective_from -> Date,
+        daily_time -> Nullable<Integer>,
+        weekly_time -> Nullable<Integer>,
+        phase_id -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    phases (id) {
+        id -> Integer,
+        period_id -> Integer,
+        name -> Text,
+        start_date -> Date,
+        end_date -> Date,
     }
 }
 
@@ -13,8 +80,54 @@ diesel::table! {
     periods (id) {
         id -> Integer,
         initial_date -> Date,
-        final_date -> Date,
+        final_date -> Nullable<Date>,
         description -> Text,
+        grading_scale -> Text,
+        program_id -> Nullable<Integer>,
+        academic_year -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    pomodoros (id) {
+        id -> Integer,
+        subject_id -> Integer,
+        started_at -> Timestamp,
+        work_minutes -> Integer,
+        focused_minutes -> Integer,
+        completed -> Bool,
+    }
+}
+
+diesel::table! {
+    programs (id) {
+        id -> Integer,
+        name -> Text,
+        required_credits -> Float,
+    }
+}
+
+diesel::table! {
+    sessions (id) {
+        id -> Integer,
+        subject_id -> Integer,
+        started_at -> Timestamp,
+        minutes -> Integer,
+    }
+}
+
+diesel::table! {
+    settings (name) {
+        name -> Text,
+        value -> Text,
+    }
+}
+
+diesel::table! {
+    subject_aliases (id) {
+        id -> Integer,
+        subject_id -> Integer,
+        alias -> Text,
     }
 }
 
@@ -25,10 +138,43 @@ diesel::table! {
         short_name -> Text,
         name -> Text,
         final_score -> Nullable<Float>,
+        credits -> Nullable<Float>,
+        passed -> Nullable<Bool>,
+        course_id -> Nullable<Integer>,
+        category -> Nullable<Text>,
+        parent_id -> Nullable<Integer>,
+        color -> Nullable<Text>,
+        archived_at -> Nullable<Date>,
     }
 }
 
+diesel::joinable!(assessments -> subjects (subject_id));
+diesel::joinable!(breaks -> periods (period_id));
 diesel::joinable!(entry -> subjects (subject_id));
+diesel::joinable!(exams -> subjects (subject_id));
+diesel::joinable!(goals -> periods (period_id));
+diesel::joinable!(goals -> phases (phase_id));
+diesel::joinable!(goals -> subjects (subject_id));
+diesel::joinable!(periods -> programs (program_id));
+diesel::joinable!(phases -> periods (period_id));
+diesel::joinable!(pomodoros -> subjects (subject_id));
+diesel::joinable!(sessions -> subjects (subject_id));
+diesel::joinable!(subject_aliases -> subjects (subject_id));
 diesel::joinable!(subjects -> periods (period_id));
 
-diesel::allow_tables_to_appear_in_same_query!(entry, periods, subjects,);
+diesel::allow_tables_to_appear_in_same_query!(
+    activity_types,
+    assessments,
+    breaks,
+    entry,
+    exams,
+    goals,
+    periods,
+    phases,
+    pomodoros,
+    programs,
+    sessions,
+    settings,
+    subject_aliases,
+    subjects,
+);
@@ -2,18 +2,36 @@
 
 fn get_usage_string() -> String {
     format!(
-        "USAGE: {} subcommand [options]\n\n\
+        "USAGE: {} [--color (auto/always/never)] subcommand [options]\n\n\
         Avaliable subcommands:\n\
-        status: Shows the current data of the study period.\n\
-        add/substract/set [when] (which subject) (minutes): Inserts/substracts a time entry.\n\
+        status [date] [--chart] [--by (subject/category/parent/tag/type)]: Shows the current data of the study period. --chart also draws the charts of the week, and --by groups the subjects.\n\
+        add/substract/set [when] (which subject) (minutes) [--type activity] [--focus (1-5)] [--at HH:MM] [--note text] [--tag tag...]: Inserts/substracts a time entry.\n\
+        config list/set/reset: Manages the settings, like the time of the day when a study day starts (day-start).\n\
+        activity add/list/remove: Manages the activity types of the entries (study, lecture, homework...).\n\
+        pomodoro (which subject) [--work time] [--break time] [--cycles n] / pomodoro stats [--plan id]: Runs pomodoros, logging their time.\n\
+        log [--from date] [--to date] [--subject (short name/id)] [--plan id]: Lists the entries with their ids.\n\
+        search (text/#tag) [--tag tag] [--plan id]: Finds the entries by the text of their notes or by tag.\n\
+        entry edit (id) [--minutes time] [--date date] [--subject (short name/id)] [--type activity] [--focus (1-5)] / entry delete (id): Fixes or deletes an entry.\n\
         plan list : Shows a list of all the study periods\n\
-        plan start [start] (end) (description): Starts a new study period.\n\
+        plan start [start] (end/open) (description) [--overlap]: Starts a new study period. Use open for periods without end date.\n\
         plan modify : Modifies current study period.\n\
         plan remove [id] [--confirm] : Deletes current or specified study period.\n\
+        program add/list/assign/unassign/status/remove: Groups plans into the academic years of a degree and shows the progress toward graduation.\n\
         subject add (short name) (long name...): Adds a new subject to the study plan.\n\
         subject modify (short name/id): Modifies a subject from the currect study plan.\n\
         subject remove (short name/id) [--confirm]: Removes a subject from the current study plan. --confirm does not require confirmation\n\
         subject list: Shows the list of subjects from the current study plan.\n\
+        subject exam add/list/remove: Manages the exams and deadlines of the subjects.\n\
+        subject assessment add/score/list/remove: Manages the graded assessments of the subjects.\n\
+        subject grade (short name/id) [--target grade] [--sync]: Computes the final grade of a subject from its assessments.\n\
+        gpa [--plan id] [--scale (0-10/0-20/A-F/4.0)]: Shows the credit-weighted average of the marks of every plan.\n\
+        analyze grades [--scale (scale)]: Correlates the study time of the marked subjects with their marks.\n\
+        analyze focus [--plan id]: Shows the effective study time and the average focus of every subject and weekday.\n\
+        analyze hours [--plan id]: Shows when the sessions with a start time were studied, by hour of the day and weekday.\n\
+        goal set (short name/id/all) [--daily time] [--weekly time] [--from date]: Sets study time goals.\n\
+        goal list/report [--plan id]: Lists the goals of a plan or shows how many of them were reached.\n\
+        chart [--plan id] [--weeks n] [--by group]: Shows bar charts and sparklines of the time dedicated to every subject.\n\
+        heatmap [--plan id] [--subject (short name/id)]: Shows a calendar heatmap of the study time of a plan.\n\
         ", crate::env::args().collect::<Vec<String>>().first().unwrap())
 }
 